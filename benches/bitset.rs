@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use monster_chess::bitset::BitSet;
+
+fn bench_bitset<const T: usize>(c: &mut Criterion, name: &str) {
+    let a = BitSet::<T>::from_data([0x5555_5555_5555_5555_5555_5555_5555_5555; T]);
+    let b = BitSet::<T>::from_data([0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f; T]);
+
+    c.bench_function(&format!("{name} bitops"), |bench| {
+        bench.iter(|| {
+            let (a, b) = (black_box(a), black_box(b));
+            (a & b) | (!a ^ b)
+        })
+    });
+    c.bench_function(&format!("{name} shifts"), |bench| {
+        bench.iter(|| (black_box(a) << black_box(129)) | (black_box(b) >> black_box(7)))
+    });
+    c.bench_function(&format!("{name} add sub"), |bench| {
+        bench.iter(|| (black_box(a) + black_box(b)) - black_box(b))
+    });
+    c.bench_function(&format!("{name} iter one bits"), |bench| {
+        bench.iter(|| black_box(b).iter_one_bits(T as u32 * 128).sum::<u32>())
+    });
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    bench_bitset::<2>(c, "BitSet<2>");
+    bench_bitset::<4>(c, "BitSet<4>");
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,5 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use monster_chess::{board::Board, games::chess::Chess};
+use monster_chess::{
+    board::{moves::GenerationMode, Board},
+    games::chess::Chess,
+};
 
 fn startpos(depth: u32) {
     let game = Chess::create();
@@ -9,7 +12,7 @@ fn startpos(depth: u32) {
         "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
     );
 
-    board.perft(depth);
+    board.perft(depth, true, GenerationMode::Normal);
 }
 
 fn criterion_benchmark(c: &mut Criterion) {
@@ -0,0 +1,28 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use monster_chess::{
+    board::{moves::GenerationMode, Board},
+    games::chess::Chess,
+};
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let game = Chess::create();
+
+    let mut magics = Board::new(&game, (8, 8), KIWIPETE);
+    c.bench_function("kiwipete (magics)", |b| {
+        b.iter(|| magics.perft(3, true, GenerationMode::Normal))
+    });
+
+    // Without magics, sliders scan each of their rays instead.
+    let mut rays = Board::new(&game, (8, 8), KIWIPETE);
+    for lookup in &mut rays.attack_lookup {
+        lookup.magics.clear();
+    }
+    c.bench_function("kiwipete (rays)", |b| {
+        b.iter(|| rays.perft(3, true, GenerationMode::Normal))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
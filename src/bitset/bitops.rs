@@ -11,16 +11,8 @@ impl<const T: usize> ops::Not for BitSet<T> {
             };
         }
 
-        BitSet::<T> {
-            bits: self
-                .bits
-                .iter()
-                .map(|el| !el)
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect(&format!(
-                    "Could not convert BitSet data vector into an array during unary operation."
-                )),
+        BitSet {
+            bits: self.bits.map(|el| !el),
         }
     }
 }
@@ -85,26 +77,25 @@ impl<const T: usize> ops::Shl<u32> for BitSet<T> {
 }
 
 impl<const T: usize> ops::ShlAssign<u32> for BitSet<T> {
-    fn shl_assign(&mut self, mut rhs: u32) {
+    /// The first word holds the most significant bits, so shifting left moves bits towards the start of `bits`.
+    fn shl_assign(&mut self, rhs: u32) {
         if T == 1 {
             self.bits = [self.bits[0] << rhs; T];
             return;
         }
 
-        while rhs > 128 {
-            *self <<= 128;
-            rhs -= 128;
-        }
-
-        let mask: u128 = u128::MAX - ((1 << (128 - rhs)) - 1); // Mask to get last `rhs` bits of integer (starting from LSB)
+        let words = (rhs / 128) as usize;
+        let shift = rhs % 128;
         for i in 0..T {
-            let bits = (self.bits[i] & mask) >> (128 - rhs); // Apply mask and shift the bits over to be the first bits of the integer
-            self.bits[i] = self.bits[i] << rhs;
-            if i == 0 {
-                continue;
-            }
-
-            self.bits[i - 1] |= bits;
+            let high = match self.bits.get(i + words) {
+                Some(bits) => bits << shift,
+                None => 0,
+            };
+            let low = match self.bits.get(i + words + 1) {
+                Some(bits) if shift != 0 => bits >> (128 - shift),
+                _ => 0,
+            };
+            self.bits[i] = high | low;
         }
     }
 }
@@ -127,26 +118,24 @@ impl<const T: usize> ops::Shr<u32> for BitSet<T> {
 }
 
 impl<const T: usize> ops::ShrAssign<u32> for BitSet<T> {
-    fn shr_assign(&mut self, mut rhs: u32) {
+    fn shr_assign(&mut self, rhs: u32) {
         if T == 1 {
             self.bits = [self.bits[0] >> rhs; T];
             return;
         }
 
-        while rhs > 128 {
-            *self >>= 128;
-            rhs -= 128;
-        }
-
-        let mask: u128 = (1 << rhs) - 1; // Mask to get first `rhs` bits of integer (starting from LSB)
+        let words = (rhs / 128) as usize;
+        let shift = rhs % 128;
         for i in (0..T).rev() {
-            let bits = (self.bits[i] & mask) << (128 - rhs); // Apply mask and shift the bits over to be the last bits of the integer
-            self.bits[i] = self.bits[i] >> rhs;
-            if i == T - 1 {
-                continue;
-            }
-
-            self.bits[i + 1] |= bits;
+            let low = match i.checked_sub(words) {
+                Some(src) => self.bits[src] >> shift,
+                None => 0,
+            };
+            let high = match i.checked_sub(words + 1) {
+                Some(src) if shift != 0 => self.bits[src] << (128 - shift),
+                _ => 0,
+            };
+            self.bits[i] = low | high;
         }
     }
 }
@@ -213,6 +202,12 @@ mod tests {
             BitSet::from_data([0, (u128::MAX >> 1) + 1]) << 1,
             BitSet::from_data([1, 0])
         );
+        assert_eq!(
+            BitSet::from_data([0, 0, 0, 3]) << 255,
+            BitSet::from_data([0, 1, (u128::MAX >> 1) + 1, 0])
+        );
+        assert_eq!(BitSet::from_data([7, 7]) << 0, BitSet::from_data([7, 7]));
+        assert_eq!(BitSet::from_data([7, 7]) << 256, BitSet::from_data([0, 0]));
     }
 
     #[test]
@@ -221,5 +216,11 @@ mod tests {
             BitSet::from_data([1, 0]) >> 1,
             BitSet::from_data([0, (u128::MAX >> 1) + 1])
         );
+        assert_eq!(
+            BitSet::from_data([3, 0, 0, 0]) >> 255,
+            BitSet::from_data([0, 0, 6, 0])
+        );
+        assert_eq!(BitSet::from_data([7, 7]) >> 0, BitSet::from_data([7, 7]));
+        assert_eq!(BitSet::from_data([7, 7]) >> 256, BitSet::from_data([0, 0]));
     }
 }
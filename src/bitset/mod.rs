@@ -2,6 +2,8 @@ mod bitops;
 mod bitscan;
 mod numops;
 mod ord;
+#[cfg(test)]
+mod proptests;
 mod shifts;
 mod util;
 
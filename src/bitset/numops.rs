@@ -14,16 +14,17 @@ impl<const T: usize> Add<BitSet<T>> for BitSet<T> {
 impl<const T: usize> AddAssign<BitSet<T>> for BitSet<T> {
     fn add_assign(&mut self, rhs: BitSet<T>) {
         if T == 1 {
-            self.bits[0] += rhs.bits[0];
+            self.bits[0] = self.bits[0].wrapping_add(rhs.bits[0]);
             return;
         }
 
-        let mut carry = *self & rhs;
-        *self ^= rhs;
-        while carry.is_set() {
-            let shifted_carry = carry << 1;
-            carry = *self & shifted_carry;
-            *self ^= shifted_carry;
+        // The last word holds the least significant bits, so carries move towards the start of `bits`.
+        let mut carry = false;
+        for (bits, rhs) in self.bits.iter_mut().zip(rhs.bits).rev() {
+            let (sum, overflowed) = bits.overflowing_add(rhs);
+            let (sum, carried) = sum.overflowing_add(carry as u128);
+            *bits = sum;
+            carry = overflowed || carried;
         }
     }
 }
@@ -41,15 +42,16 @@ impl<const T: usize> Sub<BitSet<T>> for BitSet<T> {
 impl<const T: usize> SubAssign<BitSet<T>> for BitSet<T> {
     fn sub_assign(&mut self, rhs: BitSet<T>) {
         if T == 1 {
-            self.bits[0] -= rhs.bits[0];
+            self.bits[0] = self.bits[0].wrapping_sub(rhs.bits[0]);
             return;
         }
 
-        let mut rhs = rhs.clone();
-        while rhs.is_set() {
-            let borrow = !(*self) & rhs;
-            *self ^= rhs;
-            rhs = borrow << 1;
+        let mut borrow = false;
+        for (bits, rhs) in self.bits.iter_mut().zip(rhs.bits).rev() {
+            let (difference, overflowed) = bits.overflowing_sub(rhs);
+            let (difference, borrowed) = difference.overflowing_sub(borrow as u128);
+            *bits = difference;
+            borrow = overflowed || borrowed;
         }
     }
 }
@@ -68,6 +70,11 @@ mod tests {
             BitSet::from_data([0, u128::MAX]) + BitSet::from_data([0, 1]),
             BitSet::from_data([1, 0])
         );
+        assert_eq!(
+            BitSet::from_data([0, u128::MAX, u128::MAX, u128::MAX])
+                + BitSet::from_data([0, 0, 0, 1]),
+            BitSet::from_data([1, 0, 0, 0])
+        );
     }
 
     #[test]
@@ -80,5 +87,9 @@ mod tests {
             BitSet::from_data([1, 0]) - BitSet::from_data([0, 1]),
             BitSet::from_data([0, u128::MAX])
         );
+        assert_eq!(
+            BitSet::from_data([1, 0, 0, 0]) - BitSet::from_data([0, 0, 0, 1]),
+            BitSet::from_data([0, u128::MAX, u128::MAX, u128::MAX])
+        );
     }
 }
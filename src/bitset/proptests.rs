@@ -0,0 +1,167 @@
+//! Checks every `BitSet` operation against `Model`, a plain list of bits that's simple enough to be obviously right.
+
+use std::cmp::Ordering;
+
+use proptest::prelude::*;
+
+use super::BitSet;
+
+/// A `128 * T` bit unsigned integer, as a list of its bits starting from bit `0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Model(Vec<bool>);
+
+impl Model {
+    fn new<const T: usize>(bitset: BitSet<T>) -> Model {
+        Model(
+            (0..128 * T)
+                .map(|bit| (bitset.bits[T - 1 - bit / 128] >> (bit % 128)) & 1 == 1)
+                .collect(),
+        )
+    }
+
+    fn to_bitset<const T: usize>(&self) -> BitSet<T> {
+        let mut bits = [0; T];
+        for (bit, set) in self.0.iter().enumerate() {
+            if *set {
+                bits[T - 1 - bit / 128] |= 1 << (bit % 128);
+            }
+        }
+        BitSet::from_data(bits)
+    }
+
+    fn shl(&self, shift: usize) -> Model {
+        Model(
+            (0..self.0.len())
+                .map(|bit| bit >= shift && self.0[bit - shift])
+                .collect(),
+        )
+    }
+
+    fn shr(&self, shift: usize) -> Model {
+        Model(
+            (0..self.0.len())
+                .map(|bit| bit + shift < self.0.len() && self.0[bit + shift])
+                .collect(),
+        )
+    }
+
+    fn add(&self, rhs: &Model) -> Model {
+        let mut carry = false;
+        let mut sum = Vec::with_capacity(self.0.len());
+        for (a, b) in self.0.iter().zip(&rhs.0) {
+            sum.push(a ^ b ^ carry);
+            carry = (a & b) | (carry & (a ^ b));
+        }
+        Model(sum)
+    }
+
+    fn sub(&self, rhs: &Model) -> Model {
+        let mut borrow = false;
+        let mut difference = Vec::with_capacity(self.0.len());
+        for (a, b) in self.0.iter().zip(&rhs.0) {
+            difference.push(a ^ b ^ borrow);
+            borrow = (!a & b) | (borrow & !(a ^ b));
+        }
+        Model(difference)
+    }
+
+    fn ones(&self) -> Vec<u32> {
+        (0..self.0.len() as u32)
+            .filter(|bit| self.0[*bit as usize])
+            .collect()
+    }
+
+    fn cmp(&self, rhs: &Model) -> Ordering {
+        self.0.iter().rev().cmp(rhs.0.iter().rev())
+    }
+}
+
+fn check_operations<const T: usize>(a: [u128; T], b: [u128; T], shift: u32) {
+    let (a, b) = (BitSet::from_data(a), BitSet::from_data(b));
+    let (model_a, model_b) = (Model::new(a), Model::new(b));
+    assert_eq!(model_a.to_bitset::<T>(), a);
+
+    assert_eq!(
+        Model::new(!a).0,
+        model_a.0.iter().map(|bit| !bit).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        a & b,
+        BitSet::from_data(std::array::from_fn(|i| a.bits[i] & b.bits[i]))
+    );
+    assert_eq!(
+        a | b,
+        BitSet::from_data(std::array::from_fn(|i| a.bits[i] | b.bits[i]))
+    );
+    assert_eq!(
+        a ^ b,
+        BitSet::from_data(std::array::from_fn(|i| a.bits[i] ^ b.bits[i]))
+    );
+
+    assert_eq!(Model::new(a << shift), model_a.shl(shift as usize));
+    assert_eq!(Model::new(a >> shift), model_a.shr(shift as usize));
+    let mut shifted = a;
+    shifted <<= shift;
+    assert_eq!(shifted, a << shift);
+    shifted = a;
+    shifted >>= shift;
+    assert_eq!(shifted, a >> shift);
+
+    assert_eq!(Model::new(a + b), model_a.add(&model_b));
+    assert_eq!(Model::new(a - b), model_a.sub(&model_b));
+    assert_eq!(a + b - b, a);
+
+    let ones = model_a.ones();
+    assert_eq!(a.is_empty(), ones.is_empty());
+    assert_eq!(a.is_set(), !ones.is_empty());
+    assert_eq!(a.count_ones(), ones.len() as u32);
+    assert_eq!(a.count_zeros(), 128 * T as u32 - ones.len() as u32);
+    if let (Some(first), Some(last)) = (ones.first(), ones.last()) {
+        assert_eq!(a.bitscan_forward(), *first);
+        assert_eq!(a.bitscan_reverse(), *last);
+    }
+    assert_eq!(a.iter_one_bits(128 * T as u32).collect::<Vec<_>>(), ones);
+    assert_eq!(
+        a.iter_one_bits(shift).collect::<Vec<_>>(),
+        ones.iter()
+            .copied()
+            .filter(|bit| *bit < shift)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        a.get_bits(),
+        model_a.0.iter().map(|bit| *bit as u128).collect::<Vec<_>>()
+    );
+
+    assert_eq!(a.partial_cmp(&b), Some(model_a.cmp(&model_b)));
+    assert_eq!(a.cmp(&b), model_a.cmp(&model_b));
+
+    assert_eq!(Model::new(BitSet::<T>::from_lsb(shift)).ones(), vec![shift]);
+    assert_eq!(a.has_bit(shift), model_a.0[shift as usize]);
+    assert_eq!(
+        Model::new(BitSet::<T>::from_element(a.bits[T - 1])),
+        Model::new(a).shl(128 * (T - 1)).shr(128 * (T - 1))
+    );
+}
+
+/// Words full of zeros and ones are much more likely to find carries and empty words than random ones.
+fn word() -> impl Strategy<Value = u128> {
+    prop_oneof![Just(0), Just(u128::MAX), Just(1), any::<u128>()]
+}
+
+proptest! {
+    #[test]
+    fn one_word(a in any::<u128>(), b in any::<u128>(), shift in 0..128u32) {
+        check_operations([a], [b], shift);
+    }
+
+    #[test]
+    fn two_words(a in prop::array::uniform2(word()), b in prop::array::uniform2(word()), shift in 0..256u32) {
+        check_operations(a, b, shift);
+    }
+
+    #[test]
+    fn three_words(a in prop::array::uniform3(word()), b in prop::array::uniform3(word()), shift in 0..384u32) {
+        check_operations(a, b, shift);
+    }
+}
@@ -4,7 +4,10 @@ use crate::board::BitBoard;
 
 /// I've chosen to use this little utility because of its performance in benchmarks being the best, and because it makes it the easiest to specialize to the needs of this project (in terms of both optimizations and code structure.)
 /// In this case, those needs being a way to have bigger integer sizes that are compatible with bit operations at high speeds.
-
+///
+/// A `BitSet<T>` behaves like a single unsigned integer of `128 * T` bits, stored with its most significant word first: `bits[0]` holds the highest 128 bits, and `bits[T - 1]` holds bits `0` to `127`.
+/// Bit `n` (as used by `from_lsb`, `has_bit`, the bitscans and `iter_one_bits`) is the bit worth `2^n`, so shifting left moves bits towards `bits[0]`.
+/// Like integers, addition and subtraction wrap around, shifts are only valid for less than `128 * T` bits, and sets are ordered by their value.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Ord)]
 pub struct BitSet<const T: usize> {
     pub bits: [u128; T],
@@ -15,6 +18,7 @@ impl<const T: usize> BitSet<T> {
         BitSet { bits: data }
     }
 
+    /// A set whose lowest 128 bits are `el` (which are stored in the last word.)
     pub fn from_element(el: u128) -> BitSet<T> {
         let mut arr = [0; T];
         arr[T - 1] = el;
@@ -42,7 +46,7 @@ impl<const T: usize> BitSet<T> {
             return self.bits[0] == 0;
         }
 
-        self.bits.iter().any(|el| *el == 0)
+        self.bits.iter().all(|el| *el == 0)
     }
 
     pub fn is_set(&self) -> bool {
@@ -70,14 +74,7 @@ impl<const T: usize> BitSet<T> {
         }
 
         BitSet {
-            bits: self
-                .bits
-                .iter()
-                .zip(rhs.bits)
-                .map(apply)
-                .collect::<Vec<_>>()
-                .try_into()
-                .expect(&format!("Could not convert BitSet data vector into an array when applying operation with `apply`."))
+            bits: std::array::from_fn(|i| apply((&self.bits[i], rhs.bits[i]))),
         }
     }
 
@@ -88,14 +85,9 @@ impl<const T: usize> BitSet<T> {
             return;
         }
 
-        self.bits = self
-            .bits
-            .iter()
-            .zip(rhs.bits)
-            .map(apply)
-            .collect::<Vec<_>>()
-            .try_into()
-            .expect(&format!("Could not convert BitSet data vector into an array when applying operation with `effect`."));
+        for (bits, rhs) in self.bits.iter_mut().zip(rhs.bits) {
+            *bits = apply((bits, rhs));
+        }
     }
 
     pub fn count_zeros(&self) -> u32 {
@@ -114,10 +106,11 @@ impl<const T: usize> BitSet<T> {
         }
     }
 
+    /// Every bit of the set (each either `0` or `1`), starting from bit `0`.
     /// Not a well optimized method; avoid using in hot loops.
     pub fn get_bits(&self) -> Vec<u128> {
         let mut bits: Vec<u128> = Vec::with_capacity(128 * T);
-        for container in self.bits {
+        for container in self.bits.into_iter().rev() {
             for i in 0..128 {
                 bits.push((container >> i) & 1); // Get `i`th bit of `container` and check if it is toggled on (equal to 1)
             }
@@ -7,12 +7,28 @@ pub enum UndoMoveError {
 
 pub type ActionInfo = usize;
 
+/// What an action does to the board. Most games only move pieces, but placement games put new pieces onto the board, and games with hands (eg. crazyhouse) drop pieces back onto it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActionKind {
+    /// Moves the piece on `from` to `to`.
+    Move,
+    /// Places a new piece on `to`, from a supply that never runs out (eg. stones in Go.)
+    Place,
+    /// Drops a piece from the team's hand onto `to`.
+    Drop,
+    /// Passes the turn without changing the board.
+    Pass,
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Action {
+    /// Only moves have a `from` square. Every other kind of action leaves it equal to `to`.
     pub from: u32,
+    /// Passes don't use `to` either, and leave both squares at `0`.
     pub to: u32,
     pub team: u32,
     pub piece_type: PieceType,
+    pub kind: ActionKind,
 
     /// Moves can store extra information both for optimizing `make_move` or for specifying additional variants of a move.
     ///
@@ -20,16 +36,39 @@ pub struct Action {
     pub info: ActionInfo,
 }
 
+impl Action {
+    /// A pass (or null move), which only moves on to the next turn. It doesn't belong to any piece, so its `piece_type` is left at `0`.
+    pub fn pass(team: u32) -> Action {
+        Action {
+            from: 0,
+            to: 0,
+            team,
+            piece_type: 0,
+            kind: ActionKind::Pass,
+            info: 0,
+        }
+    }
+
+    pub fn is_pass(&self) -> bool {
+        self.kind == ActionKind::Pass
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct PreviousBoard(pub BitBoard);
 
 #[derive(Copy, Clone, Debug)]
 pub struct IndexedPreviousBoard(pub usize, pub BitBoard);
 
+/// The amount of a piece type a team had in hand, as `(team, piece_type, count)`.
+#[derive(Copy, Clone, Debug)]
+pub struct PreviousHand(pub usize, pub PieceType, pub u32);
+
 #[derive(Clone, Copy, Debug)]
 pub enum HistoryUpdate {
     Team(IndexedPreviousBoard),
     Piece(IndexedPreviousBoard),
+    Hand(PreviousHand),
 }
 
 #[derive(Clone, Debug)]
@@ -59,3 +98,14 @@ pub struct HistoryMove {
     pub action: Action,
     pub state: HistoryState,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Action;
+
+    #[test]
+    fn action_size() {
+        // Every generated move is an `Action`, so it should stay small enough to copy around cheaply.
+        assert_eq!(std::mem::size_of::<Action>(), 32);
+    }
+}
@@ -0,0 +1,262 @@
+use super::{moves::GenerationMode, BitBoard, Board};
+
+/// A square's entry before a move updated it, so that undoing the move can put it back.
+#[derive(Debug, Clone, Copy)]
+struct SavedSquare {
+    square: u32,
+    team: u32,
+    attacks: BitBoard,
+    dependencies: BitBoard,
+}
+
+/// The squares each team attacks (and how many of its pieces attack each of them), kept up to date as moves are made and undone.
+/// After each move, only the pieces on squares that changed, and the pieces whose attacks depend on those squares (see `Piece::get_attack_dependencies`), have their attacks generated again.
+///
+/// Attack maps are off by default, since they make every move more expensive to make and undo. See `Board::enable_attack_maps`.
+#[derive(Debug, Clone)]
+pub struct AttackMaps {
+    /// The squares attacked by the piece on each square, which is empty for squares without a piece.
+    attacks: Vec<BitBoard>,
+    /// The squares that the attacks of the piece on each square depend on.
+    dependencies: Vec<BitBoard>,
+    /// The team of the piece on each square.
+    owners: Vec<u32>,
+    /// How many pieces of each team attack each square, indexed by team and then by square.
+    counts: Vec<Vec<u32>>,
+    /// Every square attacked by each team.
+    attacked: Vec<BitBoard>,
+
+    /// The board as of the last update, to find which squares a move changed.
+    all_pieces: BitBoard,
+    pieces: Vec<BitBoard>,
+    teams: Vec<BitBoard>,
+
+    /// The entries replaced by each move, with how many of them each move replaced in `frames`.
+    saved: Vec<SavedSquare>,
+    frames: Vec<usize>,
+}
+
+impl AttackMaps {
+    pub fn new(board: &Board) -> AttackMaps {
+        let squares = board.state.squares as usize;
+        let teams = board.state.teams.len();
+
+        let mut maps = AttackMaps {
+            attacks: vec![BitBoard::new(); squares],
+            dependencies: vec![BitBoard::new(); squares],
+            owners: vec![0; squares],
+            counts: vec![vec![0; squares]; teams],
+            attacked: vec![BitBoard::new(); teams],
+            all_pieces: board.state.all_pieces,
+            pieces: board.state.pieces.clone(),
+            teams: board.state.teams.clone(),
+            saved: Vec::new(),
+            frames: Vec::new(),
+        };
+
+        for square in maps.get_occupied().iter_one_bits(board.state.squares) {
+            maps.update_square(board, square);
+        }
+        maps
+    }
+
+    /// Every square attacked by at least one of `team`'s pieces.
+    pub fn attacked_by(&self, team: u32) -> BitBoard {
+        self.attacked[team as usize]
+    }
+
+    /// How many of `team`'s pieces attack `square`.
+    pub fn attacker_count(&self, square: u32, team: u32) -> u32 {
+        self.counts[team as usize][square as usize]
+    }
+
+    /// Every piece (of any team) that attacks `square`.
+    pub fn attackers_to(&self, square: u32) -> BitBoard {
+        let target = BitBoard::from_lsb(square);
+        let mut attackers = BitBoard::new();
+        for from in self.get_occupied().iter_one_bits(self.attacks.len() as u32) {
+            if (self.attacks[from as usize] & target).is_set() {
+                attackers |= BitBoard::from_lsb(from);
+            }
+        }
+        attackers
+    }
+
+    fn get_occupied(&self) -> BitBoard {
+        self.teams
+            .iter()
+            .fold(BitBoard::new(), |occupied, team| occupied | *team)
+    }
+
+    fn set_square(&mut self, square: u32, team: u32, attacks: BitBoard, dependencies: BitBoard) {
+        let squares = self.attacks.len() as u32;
+        let ind = square as usize;
+
+        let old_team = self.owners[ind] as usize;
+        for bit in self.attacks[ind].iter_one_bits(squares) {
+            let count = &mut self.counts[old_team][bit as usize];
+            *count -= 1;
+            if *count == 0 {
+                self.attacked[old_team] &= !BitBoard::from_lsb(bit);
+            }
+        }
+
+        for bit in attacks.iter_one_bits(squares) {
+            self.counts[team as usize][bit as usize] += 1;
+            self.attacked[team as usize] |= BitBoard::from_lsb(bit);
+        }
+
+        self.attacks[ind] = attacks;
+        self.dependencies[ind] = dependencies;
+        self.owners[ind] = team;
+    }
+
+    /// Generates the attacks of whatever piece is on `square` now.
+    fn update_square(&mut self, board: &Board, square: u32) {
+        let from = BitBoard::from_lsb(square);
+        let team = board
+            .state
+            .teams
+            .iter()
+            .position(|team| (*team & from).is_set());
+        let piece_type = board
+            .state
+            .pieces
+            .iter()
+            .position(|pieces| (*pieces & from).is_set());
+
+        match (team, piece_type) {
+            (Some(team), Some(piece_type)) => {
+                let team = team as u32;
+                let piece = board.game.pieces[piece_type];
+                let attacks =
+                    piece.get_moves(board, from, piece_type, team, GenerationMode::Attacks);
+                let dependencies =
+                    piece.get_attack_dependencies(board, from, piece_type, team, attacks);
+                self.set_square(square, team, attacks, dependencies);
+            }
+            _ => self.set_square(square, 0, BitBoard::new(), BitBoard::new()),
+        }
+    }
+
+    /// Updates the maps after a move has been made on `board`.
+    pub(crate) fn make_move(&mut self, board: &Board) {
+        let mut changed = self.all_pieces ^ board.state.all_pieces;
+        self.all_pieces = board.state.all_pieces;
+        for (old, new) in self.pieces.iter_mut().zip(&board.state.pieces) {
+            changed |= *old ^ *new;
+            *old = *new;
+        }
+        for (old, new) in self.teams.iter_mut().zip(&board.state.teams) {
+            changed |= *old ^ *new;
+            *old = *new;
+        }
+
+        let squares = board.state.squares;
+        let mut updates = changed;
+        if changed.is_set() {
+            for square in (self.get_occupied() & !changed).iter_one_bits(squares) {
+                if (self.dependencies[square as usize] & changed).is_set() {
+                    updates |= BitBoard::from_lsb(square);
+                }
+            }
+        }
+
+        let mut saved = 0;
+        for square in updates.iter_one_bits(squares) {
+            let ind = square as usize;
+            self.saved.push(SavedSquare {
+                square,
+                team: self.owners[ind],
+                attacks: self.attacks[ind],
+                dependencies: self.dependencies[ind],
+            });
+            self.update_square(board, square);
+            saved += 1;
+        }
+        self.frames.push(saved);
+    }
+
+    /// Puts the maps back the way they were before the last move, once it has been undone on `board`.
+    pub(crate) fn undo_move(&mut self, board: &Board) {
+        let saved = self.frames.pop().unwrap_or(0);
+        for _ in 0..saved {
+            let Some(square) = self.saved.pop() else {
+                break;
+            };
+            self.set_square(
+                square.square,
+                square.team,
+                square.attacks,
+                square.dependencies,
+            );
+        }
+
+        self.all_pieces = board.state.all_pieces;
+        self.pieces.clone_from(&board.state.pieces);
+        self.teams.clone_from(&board.state.teams);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{moves::GenerationMode, Board},
+        games::{chess::Chess, xiangqi::Xiangqi},
+    };
+
+    use super::AttackMaps;
+
+    fn assert_maps(board: &mut Board, depth: u32) {
+        let maps = board.state.attack_maps.as_ref().unwrap();
+        let fresh = AttackMaps::new(board);
+        assert_eq!(maps.attacks, fresh.attacks, "{}", board.to_fen());
+        assert_eq!(maps.counts, fresh.counts, "{}", board.to_fen());
+        for team in 0..board.state.teams.len() as u32 {
+            assert_eq!(
+                maps.attacked_by(team),
+                board.get_move_mask(team, GenerationMode::Attacks)
+            );
+        }
+
+        if depth == 0 {
+            return;
+        }
+        for action in board.generate_legal_moves(GenerationMode::Normal) {
+            board.make_move(&action);
+            assert_maps(board, depth - 1);
+            board.undo_move().unwrap();
+        }
+        assert_maps(board, 0);
+    }
+
+    #[test]
+    fn attack_maps() {
+        let chess = Chess::create();
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        board.enable_attack_maps();
+        assert_maps(&mut board, 2);
+        board.assert_perfts([48, 2039, 97862]);
+
+        // The pawn on d5 is attacked by the pawn on e6 and both of black's knights, and defended by the pawn on e4 and the knight on c3.
+        let maps = board.state.attack_maps.as_ref().unwrap();
+        let d5 = board.decode_position("d5".to_string()).unwrap();
+        assert_eq!(maps.attacker_count(d5, 0), 2);
+        assert_eq!(maps.attacker_count(d5, 1), 3);
+        assert_eq!(maps.attackers_to(d5).count_ones(), 5);
+
+        // Xiangqi's pieces (like the cannon) don't narrow down their dependencies, so they're updated after every move.
+        board = Board::new(
+            &xiangqi,
+            (10, 9),
+            "rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR w - - 0 1",
+        );
+        board.enable_attack_maps();
+        assert_maps(&mut board, 2);
+    }
+}
@@ -116,6 +116,86 @@ impl FenArgument for FenFullMoves {
     }
 }
 
+/// A field that's kept so FENs line up with another format, but isn't used by the game (eg. castling rights in xiangqi). It's always encoded as `-`.
+pub struct FenUnused;
+
+impl FenArgument for FenUnused {
+    #[allow(unused_variables)]
+    fn encode(&self, board: &Board) -> String {
+        "-".to_string()
+    }
+
+    #[allow(unused_variables)]
+    fn decode(&self, board: &mut Board, arg: &str) -> Result<(), FenDecodeError> {
+        Ok(())
+    }
+
+    fn duplicate(&self) -> Box<dyn FenArgument> {
+        Box::new(FenUnused)
+    }
+}
+
+/// The pieces in each team's hand, written with the same symbols as the board (eg. `QPpp`), or `-` if every hand is empty.
+/// Games with drops can opt into hands by adding this argument.
+pub struct FenHands;
+
+impl FenArgument for FenHands {
+    fn encode(&self, board: &Board) -> String {
+        let mut hands = "".to_string();
+        for (team, hand) in board.state.hands.iter().enumerate() {
+            for (piece_type, count) in hand.iter().enumerate() {
+                let symbol = board.game.pieces[piece_type]
+                    .get_piece_symbol()
+                    .get_char(team as u32);
+                for _ in 0..*count {
+                    hands.push(symbol);
+                }
+            }
+        }
+
+        if hands.is_empty() {
+            "-".to_string()
+        } else {
+            hands
+        }
+    }
+
+    fn decode(&self, board: &mut Board, arg: &str) -> Result<(), FenDecodeError> {
+        if arg == "-" {
+            return Ok(());
+        }
+
+        for char in arg.chars() {
+            let teams = board.state.teams.len() as u32;
+            let piece = (0..teams).find_map(|team| {
+                board
+                    .game
+                    .pieces
+                    .iter()
+                    .position(|piece| piece.get_piece_symbol().get_char(team) == char)
+                    .map(|piece_type| (team, piece_type))
+            });
+
+            match piece {
+                Some((team, piece_type)) => {
+                    board.state.hands[team as usize][piece_type] += 1;
+                }
+                None => {
+                    return Err(FenDecodeError::InvalidArgument(format!(
+                        "'{char}' in the hands '{arg}' is not a piece."
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn duplicate(&self) -> Box<dyn FenArgument> {
+        Box::new(FenHands)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FenDecodeError {
     InvalidArgument(String),
@@ -23,6 +23,17 @@ pub trait PostProcess {
     fn duplicate(&self) -> Box<dyn PostProcess>;
 }
 
+/// For games where loading a FEN doesn't need any extra work.
+pub struct NoPostProcess;
+impl PostProcess for NoPostProcess {
+    #[allow(unused_variables)]
+    fn apply(&self, board: &mut Board) {}
+
+    fn duplicate(&self) -> Box<dyn PostProcess> {
+        Box::new(NoPostProcess)
+    }
+}
+
 pub struct FenOptions {
     pub state: FenState,
     pub args: Vec<(String, Box<dyn FenArgument>)>,
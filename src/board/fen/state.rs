@@ -25,10 +25,16 @@ impl<'a> Board<'a> {
                 let char = chars[i];
 
                 if char.is_numeric() {
-                    board_ind += char.to_digit(10).expect(&format!(
-                        "Could not convert {char} to an integer in FEN state."
-                    ));
-                    i += 1;
+                    // Boards can be wider than 9 squares, so empty spaces may span multiple digits (eg. `10`).
+                    let mut empty_spaces = 0;
+                    while let Some(char) = chars.get(i).filter(|char| char.is_numeric()) {
+                        empty_spaces = (empty_spaces * 10)
+                            + char.to_digit(10).expect(&format!(
+                                "Could not convert {char} to an integer in FEN state."
+                            ));
+                        i += 1;
+                    }
+                    board_ind += empty_spaces;
                     continue;
                 }
 
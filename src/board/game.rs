@@ -1,13 +1,129 @@
-use super::{actions::Action, fen::FenOptions, pieces::Piece, Board};
+use super::{
+    actions::Action, fen::FenOptions, moves::MoveBuffer, pieces::Piece, Board, CustomState,
+};
 
 pub trait MoveRestrictions {
     fn is_legal(&self, board: &mut Board, action: &Action) -> bool;
+
+    /// `filter_moves` runs after `is_legal` over the whole list of legal moves, for rules that depend on which other moves are available (eg. forced captures.)
+    #[allow(unused_variables)]
+    fn filter_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {}
+
+    /// Whether `filter_moves` can change which moves are legal. It needs every legal move at once, so staged move generation generates all of them together for these games (see `MoveStage::Legal`.)
+    fn has_move_filter(&self) -> bool {
+        false
+    }
+
+    /// Removes every illegal move from `moves`, checking each of them with `is_legal` by default.
+    /// Games that can tell which moves are legal more directly (eg. from checks and pins in chess) can override this, instead of making and undoing every pseudolegal move.
+    fn retain_legal_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        moves.retain(&mut |action| self.is_legal(board, action));
+    }
+
+    /// Whether the team to move is in check, for games that have checks. Staged move generation only generates evasions while this is true.
+    #[allow(unused_variables)]
+    fn is_in_check(&self, board: &mut Board) -> bool {
+        false
+    }
+
+    /// Whether making the given action would put the enemy in check (see `MoveStage::Checks`.)
+    #[allow(unused_variables)]
+    fn gives_check(&self, board: &mut Board, action: &Action) -> bool {
+        false
+    }
+
     fn duplicate(&self) -> Box<dyn MoveRestrictions>;
 }
 
+/// Every pseudolegal move is legal, for games without any rules like check.
+pub struct NoRestrictions;
+
+impl MoveRestrictions for NoRestrictions {
+    #[allow(unused_variables)]
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        true
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(NoRestrictions)
+    }
+}
+
+/// Wraps another set of move restrictions, and requires that a capture is made whenever one is available.
+/// Variants like antichess and checkers both rely on this.
+pub struct ForcedCaptures(pub Box<dyn MoveRestrictions>);
+
+impl MoveRestrictions for ForcedCaptures {
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        self.0.is_legal(board, action)
+    }
+
+    fn retain_legal_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        self.0.retain_legal_moves(board, moves);
+    }
+
+    fn is_in_check(&self, board: &mut Board) -> bool {
+        self.0.is_in_check(board)
+    }
+
+    fn gives_check(&self, board: &mut Board, action: &Action) -> bool {
+        self.0.gives_check(board, action)
+    }
+
+    fn has_move_filter(&self) -> bool {
+        true
+    }
+
+    fn filter_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        self.0.filter_moves(board, moves);
+        if moves
+            .as_slice()
+            .iter()
+            .any(|action| board.is_capture(action))
+        {
+            moves.retain(&mut |action| board.is_capture(action));
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(ForcedCaptures(self.0.duplicate()))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameResults {
+    /// The given team has won the game.
+    Win(u32),
+    Draw,
+    Ongoing,
+}
+
+pub trait Resolution {
+    /// `resolve` takes in a board and the legal moves of the team to move, and decides whether the game has ended.
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults;
+
+    fn duplicate(&self) -> Box<dyn Resolution>;
+}
+
+/// Games that need to keep track of more than bitboards (eg. the remaining checks in three-check) can store it in `BoardState::custom`.
+/// The custom state is saved before each move, and restored when that move is undone.
+pub trait CustomStateUpdate {
+    /// The custom state of a freshly created board, before any FEN arguments are applied.
+    fn initial(&self) -> CustomState;
+
+    /// `update` is called after every move is made, so that the game can update `board.state.custom` to reflect it.
+    fn update(&self, board: &mut Board, action: &Action);
+
+    fn duplicate(&self) -> Box<dyn CustomStateUpdate>;
+}
+
 pub struct Game {
     pub pieces: Vec<&'static dyn Piece>,
+    /// How much each piece is worth (indexed like `pieces`), which `Board::see` uses to weigh up exchanges.
+    pub piece_values: Vec<i32>,
     pub move_restrictions: Box<dyn MoveRestrictions>,
+    pub resolution: Box<dyn Resolution>,
+    pub custom_state: Option<Box<dyn CustomStateUpdate>>,
     pub fen_options: FenOptions,
     pub teams: u32,
     pub turns: u32,
@@ -1,4 +1,8 @@
-use super::{pieces::Piece, AttackLookup, BitBoard, Board, Cols, Rows};
+use super::{
+    magics::get_slider_magics,
+    pieces::{Piece, PieceKind},
+    AttackLookup, BitBoard, Board, Cols, Rows,
+};
 
 pub fn generate_lookups(
     board: &Board,
@@ -13,14 +17,23 @@ pub fn generate_lookups(
         lookups.insert(i as usize, piece.generate_lookup_moves(board, from));
     }
 
-    lookups
+    let magics = if cfg!(feature = "magics") && piece.get_piece_kind() == PieceKind::Slider {
+        get_slider_magics(&lookups, board.state.squares, cols)
+    } else {
+        Vec::new()
+    };
+
+    AttackLookup {
+        directions: lookups,
+        magics,
+    }
 }
 
 impl<'a> Board<'a> {
     pub fn generate_lookups(&mut self) {
         for (ind, piece) in self.game.pieces.iter().enumerate() {
             if !piece.can_lookup() {
-                self.attack_lookup.insert(ind, vec![]);
+                self.attack_lookup.insert(ind, AttackLookup::default());
                 continue;
             }
 
@@ -0,0 +1,376 @@
+use std::sync::Arc;
+
+use super::{AttackDirections, BitBoard, Cols};
+
+/// Squares with more blockers than this don't get a magic, since their share of the table would take up `2^(bits + 1)` attacks and their magics take much longer to find.
+/// That covers every square of an 8x8 board, but (for example) only the bishops of a 10x10 board. The rest fall back to scanning each ray.
+pub const MAX_MAGIC_BITS: u32 = 12;
+
+/// Magic numbers for every ray of a slider from a single square.
+/// Each half of the blockers on those rays is multiplied by its own magic, and the top `64 - shift` bits of both products (xored together) index the slider's attacks in `SliderMagics::attacks`.
+#[derive(Debug, Clone, Copy)]
+pub struct SquareMagic {
+    pub mask: u128,
+    pub low_magic: u64,
+    pub high_magic: u64,
+    pub shift: u32,
+    pub offset: usize,
+}
+
+fn get_magic_product(relevant: u128, low_magic: u64, high_magic: u64) -> u64 {
+    (relevant as u64).wrapping_mul(low_magic) ^ ((relevant >> 64) as u64).wrapping_mul(high_magic)
+}
+
+impl SquareMagic {
+    pub fn get_index(&self, blockers: BitBoard) -> usize {
+        let relevant = blockers.bits[0] & self.mask;
+        self.offset
+            + (get_magic_product(relevant, self.low_magic, self.high_magic) >> self.shift) as usize
+    }
+}
+
+/// Magic bitboards for a group of a slider's rays (eg. the four orthogonal rays of a rook), so that all of their attacks are found with one lookup.
+/// Squares that don't have a magic (see `MAX_MAGIC_BITS`) are left as `None`.
+#[derive(Debug, Clone, Default)]
+pub struct SliderMagics {
+    pub squares: Vec<Option<SquareMagic>>,
+    pub attacks: Vec<BitBoard>,
+}
+
+impl SliderMagics {
+    /// Gets every square this group's rays from `square` reach, up to (and including) the first square in `blockers` on each of them, if `square` has a magic.
+    pub fn get_attacks(&self, square: usize, blockers: BitBoard) -> Option<BitBoard> {
+        let magic = self.squares.get(square)?.as_ref()?;
+        Some(self.attacks[magic.get_index(blockers)])
+    }
+}
+
+/// Whether a ray runs along a rank or file (rather than a diagonal), which decides which group of magics it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RayGroup {
+    Orthogonal,
+    Diagonal,
+}
+
+/// The squares of `ray` ordered from nearest to farthest from `from`, and which group it belongs to, if they form a straight, unbroken line away from it.
+fn get_ray_squares(
+    ray: BitBoard,
+    from: u32,
+    squares: u32,
+    cols: Cols,
+) -> Option<(Vec<u32>, RayGroup)> {
+    let mut ray_squares = ray.iter_one_bits(squares).collect::<Vec<_>>();
+    if ray_squares.is_empty() {
+        return None;
+    }
+    if ray_squares[0] < from {
+        ray_squares.reverse();
+    }
+
+    let position = |square: u32| ((square / cols) as i32, (square % cols) as i32);
+    let (from_row, from_col) = position(from);
+    let (first_row, first_col) = position(ray_squares[0]);
+    let (row_step, col_step) = (first_row - from_row, first_col - from_col);
+    if row_step.abs() > 1 || col_step.abs() > 1 {
+        return None;
+    }
+
+    for (ind, square) in ray_squares.iter().enumerate() {
+        let distance = ind as i32 + 1;
+        let (row, col) = position(*square);
+        if row != from_row + (row_step * distance) || col != from_col + (col_step * distance) {
+            return None;
+        }
+    }
+
+    let group = if row_step == 0 || col_step == 0 {
+        RayGroup::Orthogonal
+    } else {
+        RayGroup::Diagonal
+    };
+    Some((ray_squares, group))
+}
+
+/// Every subset of `mask`, using the Carry-Rippler trick.
+fn get_subsets(mask: u128) -> Vec<u128> {
+    let mut subsets = Vec::with_capacity(1 << mask.count_ones());
+    let mut subset: u128 = 0;
+    loop {
+        subsets.push(subset);
+        subset = subset.wrapping_sub(mask) & mask;
+        if subset == 0 {
+            return subsets;
+        }
+    }
+}
+
+fn get_blocked_attacks(rays: &[Vec<u32>], blockers: u128) -> BitBoard {
+    let mut attacks = BitBoard::new();
+    for ray_squares in rays {
+        for square in ray_squares {
+            attacks |= BitBoard::from_lsb(*square);
+            if blockers & (1 << square) != 0 {
+                break;
+            }
+        }
+    }
+    attacks
+}
+
+/// Magics for larger masks are much easier to find with one more bit than they strictly need, at the cost of twice the space.
+fn get_index_bits(mask: u128) -> u32 {
+    let bits = mask.count_ones();
+    if bits > 8 {
+        bits + 1
+    } else {
+        bits.max(1)
+    }
+}
+
+/// A xorshift64* generator, so that the same magics are found every time.
+struct MagicRng(u64);
+
+impl MagicRng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(2685821657736338717)
+    }
+
+    /// Magics with only a few bits set are far more likely to work.
+    fn next_sparse(&mut self) -> u64 {
+        self.next() & self.next() & self.next()
+    }
+}
+
+/// Searches for a magic that maps every subset of `mask` onto a slot that only holds its own attacks, and fills `table` with them.
+fn find_magic(
+    rng: &mut MagicRng,
+    mask: u128,
+    subsets: &[u128],
+    attacks: &[BitBoard],
+    table: &mut [BitBoard],
+) -> SquareMagic {
+    let bits = get_index_bits(mask);
+    let shift = 64 - bits;
+
+    // Each slot remembers the attempt that filled it, so the table never has to be cleared between attempts.
+    let mut filled_by = vec![0u32; table.len()];
+    let mut attempt = 0;
+    loop {
+        let (low_magic, high_magic) = (rng.next_sparse(), rng.next_sparse());
+        // Good magics move plenty of the mask's bits into the top of the product (but small masks might not have enough bits to do so.)
+        if mask.count_ones() > 8
+            && (get_magic_product(mask, low_magic, high_magic) >> 56).count_ones() < 6
+        {
+            continue;
+        }
+
+        attempt += 1;
+        let works = subsets.iter().zip(attacks).all(|(subset, subset_attacks)| {
+            let index = (get_magic_product(*subset, low_magic, high_magic) >> shift) as usize;
+            if filled_by[index] != attempt {
+                filled_by[index] = attempt;
+                table[index] = *subset_attacks;
+                true
+            } else {
+                table[index] == *subset_attacks
+            }
+        });
+
+        if works {
+            return SquareMagic {
+                mask,
+                low_magic,
+                high_magic,
+                shift,
+                offset: 0,
+            };
+        }
+    }
+}
+
+/// Finds magics for every square of a group of rays, given the rays from each square.
+fn generate_slider_magics(rays: &[Vec<Vec<u32>>]) -> SliderMagics {
+    let mut magics = SliderMagics::default();
+    let mut rng = MagicRng(0x2545F4914F6CDD1D);
+
+    for square_rays in rays {
+        // A blocker on the last square of a ray doesn't change which squares it reaches.
+        let mut mask: u128 = 0;
+        for ray_squares in square_rays {
+            for square in &ray_squares[..ray_squares.len() - 1] {
+                mask |= 1 << square;
+            }
+        }
+
+        if square_rays.is_empty() || mask.count_ones() > MAX_MAGIC_BITS {
+            magics.squares.push(None);
+            continue;
+        }
+
+        let subsets = get_subsets(mask);
+        let attacks = subsets
+            .iter()
+            .map(|subset| get_blocked_attacks(square_rays, *subset))
+            .collect::<Vec<_>>();
+
+        let offset = magics.attacks.len();
+        magics
+            .attacks
+            .resize(offset + (1 << get_index_bits(mask)), BitBoard::new());
+        let mut magic = find_magic(
+            &mut rng,
+            mask,
+            &subsets,
+            &attacks,
+            &mut magics.attacks[offset..],
+        );
+        magic.offset = offset;
+        magics.squares.push(Some(magic));
+    }
+
+    magics
+}
+
+/// Magics only depend on the board's size and the rays they cover, so boards of the same size share them instead of searching for them again.
+type MagicCache = Vec<((u32, Cols), Vec<BitBoard>, Arc<SliderMagics>)>;
+
+static MAGIC_CACHE: std::sync::Mutex<MagicCache> = std::sync::Mutex::new(Vec::new());
+
+/// Finds the magics for a slider's `lookup`, with one `SliderMagics` for its orthogonal rays and another for its diagonal ones (if it has any of each.)
+/// These are shared between every slider with the same rays on the same size of board, so a queen uses the same tables as a rook and a bishop.
+pub fn get_slider_magics(
+    lookup: &[AttackDirections],
+    squares: u32,
+    cols: Cols,
+) -> Vec<Arc<SliderMagics>> {
+    let mut groups = Vec::new();
+    for group in [RayGroup::Orthogonal, RayGroup::Diagonal] {
+        let mut rays = Vec::with_capacity(lookup.len());
+        let mut masks = Vec::with_capacity(lookup.len());
+        for (from, directions) in lookup.iter().enumerate() {
+            let mut square_rays = Vec::new();
+            let mut mask = BitBoard::new();
+            for ray in directions {
+                let Some((ray_squares, ray_group)) =
+                    get_ray_squares(*ray, from as u32, squares, cols)
+                else {
+                    continue;
+                };
+                // Combined slots can happen to be a single ray (eg. a bishop in the corner), which shouldn't be counted twice.
+                if ray_group == group && (mask & *ray) != *ray {
+                    mask |= *ray;
+                    square_rays.push(ray_squares);
+                }
+            }
+            rays.push(square_rays);
+            masks.push(mask);
+        }
+
+        if masks.iter().all(|mask| mask.is_empty()) {
+            continue;
+        }
+
+        let mut cache = MAGIC_CACHE.lock().unwrap();
+        let cached = cache
+            .iter()
+            .find(|(size, cached_masks, _)| *size == (squares, cols) && *cached_masks == masks);
+        let magics = match cached {
+            Some((_, _, magics)) => magics.clone(),
+            None => {
+                let magics = Arc::new(generate_slider_magics(&rays));
+                cache.push(((squares, cols), masks, magics.clone()));
+                magics
+            }
+        };
+        groups.push(magics);
+    }
+    groups
+}
+
+#[cfg(all(test, feature = "magics"))]
+mod tests {
+    use crate::{
+        board::{pieces::PieceKind, BitBoard, Board},
+        games::chess::{pieces::get_blocked_ray, Capablanca, Chess},
+    };
+
+    #[test]
+    fn slider_magics() {
+        let chess = Chess::create();
+        let capablanca = Capablanca::create();
+        let mut seed: u128 = 0x2545F4914F6CDD1D;
+
+        for (game, size) in [
+            (&chess, (8, 8)),
+            (&chess, (10, 10)),
+            (&chess, (8, 16)),
+            (&chess, (16, 4)),
+            (&capablanca, (8, 10)),
+        ] {
+            let board = Board::empty(game, size);
+            for (piece_type, piece) in game.pieces.iter().enumerate() {
+                let lookup = &board.attack_lookup[piece_type];
+                if piece.get_piece_kind() != PieceKind::Slider {
+                    assert!(lookup.magics.is_empty());
+                    continue;
+                }
+
+                // Queens have eight rays, and the other sliders have four (followed by their combined slot, and the knight's moves for compound pieces.)
+                let rays = if lookup[0].len() == 9 { 8 } else { 4 };
+                for square in 0..board.state.squares as usize {
+                    for _ in 0..16 {
+                        seed ^= seed << 13;
+                        seed ^= seed >> 7;
+                        seed ^= seed << 17;
+                        let blockers = BitBoard::from_element(seed & (seed >> 3));
+
+                        let from = BitBoard::from_lsb(square as u32);
+                        let mut scanned = BitBoard::new();
+                        for dir in 0..rays {
+                            scanned |= get_blocked_ray(from, square, dir, lookup, blockers);
+                        }
+
+                        if let Some(attacks) = lookup.get_slider_attacks(square, blockers) {
+                            assert_eq!(attacks, scanned);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Every square of an 8x8 board has magics, but a 10x10 board's rooks always have too many blockers for them.
+        for (size, rook_magics, bishop_magics) in [((8, 8), 64, 64), ((10, 10), 0, 96)] {
+            let board = Board::empty(&chess, size);
+            let count_magics = |piece_type: usize| {
+                (0..board.state.squares as usize)
+                    .filter(|square| {
+                        board.attack_lookup[piece_type]
+                            .get_slider_attacks(*square, BitBoard::new())
+                            .is_some()
+                    })
+                    .count()
+            };
+            assert_eq!(count_magics(3), rook_magics);
+            assert_eq!(count_magics(2), bishop_magics);
+        }
+    }
+
+    #[test]
+    fn slider_magics_perft() {
+        let chess = Chess::create();
+        let mut board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+
+        // Bishops, rooks and queens all find their attacks with magics here.
+        for piece_type in 2..5 {
+            assert!(!board.attack_lookup[piece_type].magics.is_empty());
+        }
+        board.assert_perfts([48, 2039, 97862]);
+    }
+}
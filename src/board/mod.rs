@@ -1,11 +1,15 @@
 pub mod actions;
+pub mod attacks;
 pub mod edges;
 pub mod fen;
 pub mod game;
 pub mod lookups;
+pub mod magics;
+pub mod moves;
 pub mod perft;
 pub mod pieces;
 pub mod positions;
+pub mod see;
 mod util;
 
 pub use util::*;
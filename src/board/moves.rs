@@ -0,0 +1,359 @@
+use std::{mem, ops::Deref};
+
+use arrayvec::ArrayVec;
+
+use super::{actions::Action, Board};
+
+/// What moves are being generated for, which is passed down to every piece (see `Piece::get_moves`.)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GenerationMode {
+    /// The moves that pieces can actually make.
+    #[default]
+    Normal,
+    /// Every square that pieces attack, whether or not they could move there (eg. pawns attack diagonally even when there's nothing to capture.)
+    /// This is what checks are found with, so pieces that can't capture where they move (like the xiangqi cannon) only include their captures.
+    Attacks,
+    /// Modes for games that need to generate moves in some other way, which pieces from other games treat like `Normal`.
+    Custom(u32),
+}
+
+/// Chess has at most 218 legal moves in any position, so most games never need more room than this.
+pub const MAX_MOVES: usize = 256;
+
+/// Anything that moves can be generated into (see `Board::generate_moves_into`.)
+/// Search loops can generate into a `MoveList` (or any other buffer they keep around) to avoid allocating a new `Vec` for every position.
+pub trait MoveBuffer {
+    fn push(&mut self, action: Action);
+
+    fn as_slice(&self) -> &[Action];
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&Action) -> bool);
+
+    fn clear(&mut self);
+}
+
+impl MoveBuffer for Vec<Action> {
+    fn push(&mut self, action: Action) {
+        Vec::push(self, action);
+    }
+
+    fn as_slice(&self) -> &[Action] {
+        self
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&Action) -> bool) {
+        Vec::retain(self, |action| keep(action));
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self);
+    }
+}
+
+/// Pushing past the capacity of an `ArrayVec` panics, so these should only be used for games with a known limit on their moves.
+impl<const N: usize> MoveBuffer for ArrayVec<Action, N> {
+    fn push(&mut self, action: Action) {
+        ArrayVec::push(self, action);
+    }
+
+    fn as_slice(&self) -> &[Action] {
+        self
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&Action) -> bool) {
+        ArrayVec::retain(self, |action| keep(action));
+    }
+
+    fn clear(&mut self) {
+        ArrayVec::clear(self);
+    }
+}
+
+/// A list of moves that lives on the stack, for up to `MAX_MOVES` moves.
+/// Some games have far more moves than that (like the amazons, with 2,176 moves from the starting position), so it moves onto the heap instead of running out of room.
+#[derive(Clone, Debug, Default)]
+pub struct MoveList {
+    stack: ArrayVec<Action, MAX_MOVES>,
+    heap: Vec<Action>,
+}
+
+impl MoveList {
+    pub fn new() -> MoveList {
+        MoveList::default()
+    }
+}
+
+impl MoveBuffer for MoveList {
+    fn push(&mut self, action: Action) {
+        if !self.heap.is_empty() {
+            self.heap.push(action);
+        } else if let Err(err) = self.stack.try_push(action) {
+            self.heap.reserve(MAX_MOVES * 2);
+            self.heap.extend(self.stack.drain(..));
+            self.heap.push(err.element());
+        }
+    }
+
+    fn as_slice(&self) -> &[Action] {
+        if self.heap.is_empty() {
+            &self.stack
+        } else {
+            &self.heap
+        }
+    }
+
+    fn retain(&mut self, keep: &mut dyn FnMut(&Action) -> bool) {
+        if self.heap.is_empty() {
+            self.stack.retain(|action| keep(action));
+        } else {
+            self.heap.retain(|action| keep(action));
+        }
+    }
+
+    fn clear(&mut self) {
+        self.stack.clear();
+        self.heap.clear();
+    }
+}
+
+impl Deref for MoveList {
+    type Target = [Action];
+
+    fn deref(&self) -> &[Action] {
+        self.as_slice()
+    }
+}
+
+/// The stages that legal moves can be generated in (see `Board::generate_stage_into`), so that engines can look at the most promising moves first, and only generate the rest when they need them.
+/// Outside of check, every legal move belongs to exactly one of `Captures`, `Promotions`, `Checks` and `Quiets`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MoveStage {
+    /// Actions that capture an enemy piece, including promotions that capture.
+    Captures,
+    /// Promotions that don't capture anything.
+    Promotions,
+    /// Quiet actions that put the enemy in check (see `MoveRestrictions::gives_check`.)
+    Checks,
+    /// Every other action.
+    Quiets,
+    /// Every legal action, but only while the team to move is in check (see `MoveRestrictions::is_in_check`.)
+    Evasions,
+    /// Every legal action, for games whose legal moves can't be split into stages (see `MoveRestrictions::has_move_filter`.)
+    Legal,
+}
+
+/// Every stage, in the order `MovePicker` goes through them outside of check.
+pub const MAIN_STAGES: [MoveStage; 4] = [
+    MoveStage::Captures,
+    MoveStage::Promotions,
+    MoveStage::Checks,
+    MoveStage::Quiets,
+];
+
+/// Picks legal moves one stage at a time, only generating each stage once the moves of the one before it have run out.
+/// A search that cuts off after the first few captures never has to generate any quiet moves.
+pub struct MovePicker {
+    stages: ArrayVec<MoveStage, 6>,
+    stage: usize,
+    moves: MoveList,
+    index: usize,
+    mode: GenerationMode,
+    /// Checks and quiets are generated together (see `Board::generate_quiets_into`), so whichever of them comes second is kept here until its stage.
+    quiets: Option<MoveList>,
+}
+
+impl MovePicker {
+    /// Picks every legal move: just the evasions when in check, and otherwise captures, promotions, checks and then quiets.
+    /// Games with a move filter (eg. the forced captures of antichess) pick all of their legal moves at once.
+    pub fn new(board: &mut Board, mode: GenerationMode) -> MovePicker {
+        let move_restrictions = &board.game.move_restrictions;
+        if move_restrictions.is_in_check(board) {
+            MovePicker::with_stages(&[MoveStage::Evasions], mode)
+        } else if move_restrictions.has_move_filter() {
+            MovePicker::with_stages(&[MoveStage::Legal], mode)
+        } else {
+            MovePicker::with_stages(&MAIN_STAGES, mode)
+        }
+    }
+
+    /// Picks only the moves of the given stages, in order (eg. just `MoveStage::Captures` for a quiescence search.)
+    pub fn with_stages(stages: &[MoveStage], mode: GenerationMode) -> MovePicker {
+        MovePicker {
+            stages: stages.iter().copied().collect(),
+            stage: 0,
+            moves: MoveList::new(),
+            index: 0,
+            mode,
+            quiets: None,
+        }
+    }
+
+    /// The stage of the last move that was picked.
+    pub fn stage(&self) -> Option<MoveStage> {
+        self.stage.checked_sub(1).map(|stage| self.stages[stage])
+    }
+
+    /// The board has to be in the same position every time this is called.
+    pub fn next(&mut self, board: &mut Board) -> Option<Action> {
+        loop {
+            if let Some(action) = self.moves.get(self.index) {
+                self.index += 1;
+                return Some(*action);
+            }
+
+            let stage = *self.stages.get(self.stage)?;
+            self.stage += 1;
+            self.index = 0;
+            self.moves.clear();
+            match (stage, &mut self.quiets) {
+                (MoveStage::Checks | MoveStage::Quiets, Some(quiets)) => {
+                    mem::swap(&mut self.moves, quiets);
+                }
+                (MoveStage::Checks, None) => {
+                    let mut quiets = MoveList::new();
+                    board.generate_quiets_into(&mut self.moves, &mut quiets, self.mode);
+                    self.quiets = Some(quiets);
+                }
+                (MoveStage::Quiets, None) => {
+                    let mut checks = MoveList::new();
+                    board.generate_quiets_into(&mut checks, &mut self.moves, self.mode);
+                    self.quiets = Some(checks);
+                }
+                (stage, _) => board.generate_stage_into(&mut self.moves, stage, self.mode),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{actions::Action, Board},
+        games::chess::{Antichess, Chess},
+    };
+
+    use super::{GenerationMode, MoveBuffer, MoveList, MovePicker, MoveStage, MAX_MOVES};
+
+    #[test]
+    fn move_list_overflow() {
+        let mut moves = MoveList::new();
+        for team in 0..(MAX_MOVES as u32 + 10) {
+            moves.push(Action::pass(team));
+        }
+
+        assert_eq!(moves.len(), MAX_MOVES + 10);
+        assert!(moves
+            .iter()
+            .enumerate()
+            .all(|(ind, action)| action.team == ind as u32));
+
+        moves.retain(&mut |action| action.team % 2 == 0);
+        assert_eq!(moves.len(), (MAX_MOVES + 10) / 2);
+
+        moves.clear();
+        moves.push(Action::pass(0));
+        assert_eq!(moves.len(), 1);
+    }
+
+    fn count_stages(board: &mut Board) -> Vec<(MoveStage, usize)> {
+        let mut counts: Vec<(MoveStage, usize)> = Vec::new();
+        let mut picked = Vec::new();
+        let mut picker = MovePicker::new(board, GenerationMode::Normal);
+        while let Some(action) = picker.next(board) {
+            let stage = picker.stage().unwrap();
+            match counts.last_mut() {
+                Some((last, count)) if *last == stage => *count += 1,
+                _ => counts.push((stage, 1)),
+            }
+            picked.push(board.encode_action(&action));
+        }
+
+        let mut legal_moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        picked.sort();
+        legal_moves.sort();
+        assert_eq!(picked, legal_moves);
+
+        counts
+    }
+
+    #[test]
+    fn staged_moves() {
+        let chess = Chess::create();
+        let mut board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        assert_eq!(
+            count_stages(&mut board),
+            vec![(MoveStage::Captures, 8), (MoveStage::Quiets, 40)]
+        );
+
+        // Rxf4+ is a capture that gives check, and g3+ is a quiet check.
+        board = Board::new(&chess, (8, 8), "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1");
+        assert_eq!(
+            count_stages(&mut board),
+            vec![
+                (MoveStage::Captures, 1),
+                (MoveStage::Checks, 1),
+                (MoveStage::Quiets, 12)
+            ]
+        );
+
+        // The pawn on e5 can take the one on d5 en passant.
+        board = Board::new(&chess, (8, 8), "6k1/8/8/3pP3/8/8/8/6K1 w - d6 0 1");
+        assert_eq!(
+            count_stages(&mut board),
+            vec![(MoveStage::Captures, 1), (MoveStage::Quiets, 6)]
+        );
+
+        // White is in check from the bishop on b6.
+        board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        );
+        assert_eq!(count_stages(&mut board), vec![(MoveStage::Evasions, 6)]);
+
+        // Black's pawn on b2 can promote by moving (4 moves) or by capturing the rook on a1 (4 more.)
+        board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 b kq - 0 1",
+        );
+        let mut picker = MovePicker::with_stages(&[MoveStage::Promotions], GenerationMode::Normal);
+        let mut promotions = 0;
+        while let Some(action) = picker.next(&mut board) {
+            assert_eq!(
+                action.from,
+                board.decode_position("b2".to_string()).unwrap()
+            );
+            promotions += 1;
+        }
+        assert_eq!(promotions, 4);
+
+        // Antichess has to capture whenever it can, so after 1.d4 e5 the only legal move is dxe5.
+        let antichess = Antichess::create();
+        board = Board::new(
+            &antichess,
+            (8, 8),
+            "rnbqkbnr/pppp1ppp/8/4p3/3P4/8/PPP1PPPP/RNBQKBNR w - - 0 2",
+        );
+        assert_eq!(count_stages(&mut board), vec![(MoveStage::Legal, 1)]);
+
+        // Picking only some of the stages still leaves out the moves that the forced capture rules out.
+        let mut picker = MovePicker::with_stages(&[MoveStage::Quiets], GenerationMode::Normal);
+        assert!(picker.next(&mut board).is_none());
+        picker = MovePicker::with_stages(&[MoveStage::Captures], GenerationMode::Normal);
+        assert_eq!(
+            picker
+                .next(&mut board)
+                .map(|action| board.encode_action(&action)),
+            Some("d4e5".to_string())
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use super::{
     actions::{Action, HistoryMove, UndoMoveError},
     edges::Edges,
-    game::Game,
+    game::{Game, GameResults},
+    moves::{GenerationMode, MoveList},
     pieces::Piece,
     Board,
 };
@@ -26,20 +27,25 @@ impl PerftResults {
 }
 
 impl<'a> Board<'a> {
-    pub fn perft(&mut self, depth: u32, legality: bool) -> u64 {
+    /// Counts the positions `depth` moves ahead, only counting legal moves when `legality` is set.
+    pub fn perft(&mut self, depth: u32, legality: bool, mode: GenerationMode) -> u64 {
         if depth == 0 {
             return 1;
         }
 
         let mut nodes = 0;
-        let moves = if legality {
-            self.generate_legal_moves(0)
+        let mut moves = MoveList::new();
+        if legality {
+            self.generate_legal_moves_into(&mut moves, mode);
+            if self.game.resolution.resolve(self, &moves) != GameResults::Ongoing {
+                return 0;
+            }
         } else {
-            self.generate_moves(0)
-        };
-        for node in moves {
-            self.make_move(&node);
-            nodes += self.perft(depth - 1, legality);
+            self.generate_moves_into(&mut moves, mode);
+        }
+        for node in moves.iter() {
+            self.make_move(node);
+            nodes += self.perft(depth - 1, legality, mode);
             self.undo_move();
         }
 
@@ -47,7 +53,7 @@ impl<'a> Board<'a> {
     }
 
     pub fn assert_perft(&mut self, depth: u32, true_nodes: u64) {
-        let nodes = self.perft(depth, true);
+        let nodes = self.perft(depth, true, GenerationMode::Normal);
         assert_eq!(
             nodes,
             true_nodes,
@@ -66,7 +72,7 @@ impl<'a> Board<'a> {
         }
     }
 
-    pub fn branch_perft(&mut self, depth: u32) -> PerftResults {
+    pub fn branch_perft(&mut self, depth: u32, mode: GenerationMode) -> PerftResults {
         if depth == 0 {
             return PerftResults {
                 nodes: 1,
@@ -76,11 +82,17 @@ impl<'a> Board<'a> {
 
         let mut nodes = 0;
         let mut branches: Vec<PerftBranch> = vec![];
-        for node in self.generate_legal_moves(0) {
-            self.make_move(&node);
-            let results = self.branch_perft(depth - 1);
+        let mut moves = MoveList::new();
+        self.generate_legal_moves_into(&mut moves, mode);
+        if self.game.resolution.resolve(self, &moves) != GameResults::Ongoing {
+            return PerftResults { nodes: 0, branches };
+        }
+
+        for node in moves.iter() {
+            self.make_move(node);
+            let results = self.branch_perft(depth - 1, mode);
             nodes += results.nodes;
-            branches.push((self.encode_action(&node), results));
+            branches.push((self.encode_action(node), results));
             self.undo_move();
         }
 
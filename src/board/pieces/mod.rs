@@ -1,9 +1,10 @@
 use super::{
     actions::{
-        Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard,
-        UndoMoveError,
+        Action, ActionKind, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard,
+        PreviousBoard, PreviousHand, UndoMoveError,
     },
     game::Game,
+    moves::{GenerationMode, MoveBuffer, MoveList, MoveStage},
     AttackDirections, AttackLookup, BitBoard, Board, BoardState, Cols, PieceType, Rows,
 };
 
@@ -12,6 +13,34 @@ pub enum PieceSymbol {
     TeamSymbol(Vec<char>),
 }
 
+impl PieceSymbol {
+    /// The character this piece is written as for the given team, without any FEN team markers.
+    pub fn get_char(&self, team: u32) -> char {
+        match self {
+            PieceSymbol::Char(char) => {
+                if team == 0 {
+                    char.to_ascii_uppercase()
+                } else {
+                    char.to_ascii_lowercase()
+                }
+            }
+            PieceSymbol::TeamSymbol(teams) => teams[team as usize],
+        }
+    }
+}
+
+/// The common kinds of pieces, which some of `Piece`'s defaults can do less work for (see `Piece::get_piece_kind`.)
+/// Leapers and sliders both capture by moving onto the enemy pieces they attack.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PieceKind {
+    /// Attacks the same squares wherever the other pieces are (eg. knights and kings.)
+    Leaper,
+    /// Attacks along rays that stop at the first piece in the way (eg. rooks and bishops.)
+    Slider,
+    /// Anything else, which the defaults can't assume anything about.
+    Other,
+}
+
 const NORMAL_MOVE: usize = 0;
 
 pub trait Piece {
@@ -24,6 +53,28 @@ pub trait Piece {
         0
     }
 
+    /// Moves are written as their `from` and `to` squares followed by their info (eg. `e7e8q`), placements as their square (eg. `d3`), and drops as the piece and its square (eg. `P@e4`).
+    /// Games with other notations (like Go's SGF coordinates) can override this.
+    fn encode_action(&self, board: &Board, action: &Action) -> String {
+        match action.kind {
+            ActionKind::Move => format!(
+                "{}{}{}",
+                board.encode_position(action.from),
+                board.encode_position(action.to),
+                self.format_info(board, action.info)
+            ),
+            ActionKind::Place => board.encode_position(action.to),
+            ActionKind::Drop => {
+                let symbol = match self.get_piece_symbol() {
+                    PieceSymbol::Char(char) => char.to_ascii_uppercase(),
+                    symbol => symbol.get_char(action.team),
+                };
+                format!("{}@{}", symbol, board.encode_position(action.to))
+            }
+            ActionKind::Pass => board.encode_action(action),
+        }
+    }
+
     fn can_lookup(&self) -> bool;
 
     fn get_attack_lookup<'a>(
@@ -40,7 +91,7 @@ pub trait Piece {
         from: BitBoard,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) -> BitBoard;
     fn can_move_mask(
         &self,
@@ -49,17 +100,76 @@ pub trait Piece {
         from_bit: u32,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
         to: BitBoard,
     ) -> BitBoard {
         self.get_moves(board, from, piece_type, team, mode)
     }
 
+    /// Which of the common kinds of pieces this is, if any. Pieces are `PieceKind::Other` by default.
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Other
+    }
+
+    /// The squares whose pieces can change which squares this piece attacks, given the squares it attacks now (see `AttackMaps`.)
+    /// Leapers don't depend on any square, and sliders only depend on the squares they attack (where they're blocked.)
+    /// Every other kind of piece depends on every square, so its attacks are generated again after every move, unless it overrides this.
+    #[allow(unused_variables)]
+    fn get_attack_dependencies(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        attacks: BitBoard,
+    ) -> BitBoard {
+        match self.get_piece_kind() {
+            PieceKind::Leaper => BitBoard::new(),
+            PieceKind::Slider => attacks,
+            PieceKind::Other => BitBoard::max(),
+        }
+    }
+
     #[allow(unused_variables)]
     fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
         Vec::new()
     }
 
+    /// Whether or not the given action captures an enemy piece. Pieces with captures that don't land on the captured piece (eg. en passant) should override this.
+    fn is_capture(&self, board: &Board, action: &Action) -> bool {
+        let to = BitBoard::from_lsb(action.to);
+        action.kind == ActionKind::Move
+            && (to & board.state.all_pieces & !board.state.teams[action.team as usize]).is_set()
+    }
+
+    /// Whether or not the given action promotes this piece into another one.
+    fn is_promotion(&self, board: &Board, action: &Action) -> bool {
+        self.get_promotion(board, action).is_some()
+    }
+
+    /// The piece type the given action promotes this piece into, if it's a promotion.
+    #[allow(unused_variables)]
+    fn get_promotion(&self, board: &Board, action: &Action) -> Option<PieceType> {
+        None
+    }
+
+    /// The squares the given action lands on, in order from `from` to `to`. Pieces that can land on several squares in one move (eg. multi-jumps in checkers) should override this.
+    #[allow(unused_variables)]
+    fn get_path(&self, board: &Board, action: &Action) -> Vec<u32> {
+        vec![action.from, action.to]
+    }
+
+    /// Which of the `Captures`, `Promotions` and `Quiets` stages the given action belongs to (see `MoveStage`.)
+    fn get_stage(&self, board: &Board, action: &Action) -> MoveStage {
+        if self.is_capture(board, action) {
+            MoveStage::Captures
+        } else if self.is_promotion(board, action) {
+            MoveStage::Promotions
+        } else {
+            MoveStage::Quiets
+        }
+    }
+
     fn make_capture_move(
         &self,
         board: &mut Board,
@@ -153,43 +263,90 @@ pub trait Piece {
         board.state.first_move &= !from;
     }
 
-    fn make_move(&self, board: &mut Board, action: &Action) {
-        let from = BitBoard::from_lsb(action.from);
+    /// Puts a new piece on `action.to`, from a supply that never runs out.
+    fn make_placement(&self, board: &mut Board, action: &Action) {
+        let color: usize = action.team as usize;
+        let piece_type = action.piece_type;
         let to = BitBoard::from_lsb(action.to);
 
-        if (board.state.all_pieces & to).is_empty() {
-            self.make_normal_move(board, action, action.piece_type, from, to);
-        } else {
-            self.make_capture_move(board, action, action.piece_type, from, to);
+        board.history.push(HistoryMove {
+            action: *action,
+            state: HistoryState::Single {
+                team: IndexedPreviousBoard(color, board.state.teams[color]),
+                piece: IndexedPreviousBoard(piece_type, board.state.pieces[piece_type]),
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+            },
+        });
+
+        board.state.teams[color] |= to;
+        board.state.pieces[piece_type] |= to;
+        board.state.all_pieces |= to;
+    }
+
+    /// Takes the piece out of its team's hand, and puts it on `action.to`.
+    fn make_drop(&self, board: &mut Board, action: &Action) {
+        let color: usize = action.team as usize;
+        let piece_type = action.piece_type;
+        let to = BitBoard::from_lsb(action.to);
+
+        board.history.push(HistoryMove {
+            action: *action,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        piece_type,
+                        board.state.pieces[piece_type],
+                    )),
+                    HistoryUpdate::Hand(PreviousHand(
+                        color,
+                        piece_type,
+                        board.state.hands[color][piece_type],
+                    )),
+                ],
+            },
+        });
+
+        board.state.teams[color] |= to;
+        board.state.pieces[piece_type] |= to;
+        board.state.all_pieces |= to;
+        board.state.hands[color][piece_type] -= 1;
+    }
+
+    /// Pieces that override `make_move` only need to handle the kinds of actions they generate.
+    fn make_move(&self, board: &mut Board, action: &Action) {
+        match action.kind {
+            ActionKind::Move => {
+                let from = BitBoard::from_lsb(action.from);
+                let to = BitBoard::from_lsb(action.to);
+
+                if (board.state.all_pieces & to).is_empty() {
+                    self.make_normal_move(board, action, action.piece_type, from, to);
+                } else {
+                    self.make_capture_move(board, action, action.piece_type, from, to);
+                }
+            }
+            ActionKind::Place => self.make_placement(board, action),
+            ActionKind::Drop => self.make_drop(board, action),
+            ActionKind::Pass => {
+                board.make_pass(action);
+                return;
+            }
         }
 
         self.update_turns(board);
     }
 
     fn update_turns(&self, board: &mut Board) {
-        board.state.turns += 1;
-        board.state.current_turn = board.state.turn_lookup[board.state.current_turn as usize];
-        if board.state.current_turn == 0 {
-            board.state.sub_moves += 1;
-
-            if board.state.moving_team == 0 {
-                board.state.full_moves += 1;
-            }
-            board.state.moving_team = board.state.team_lookup[board.state.moving_team as usize];
-        };
+        board.state.update_turns();
     }
 
+    #[allow(unused_variables)]
     fn undo_move(&self, state: &mut BoardState, game: &Game, history_move: &HistoryMove) {
-        state.turns -= 1;
-        state.current_turn = state.turn_reverse_lookup[state.current_turn as usize];
-        if state.current_turn == game.turns - 1 {
-            state.moving_team = state.team_reverse_lookup[state.moving_team as usize];
-            state.sub_moves -= 1;
-
-            if state.moving_team == 0 {
-                state.full_moves -= 1;
-            }
-        }
+        state.undo_turns();
 
         match &history_move.state {
             HistoryState::Single {
@@ -218,6 +375,9 @@ pub trait Piece {
                         HistoryUpdate::Piece(piece) => {
                             state.pieces[piece.0] = piece.1;
                         }
+                        HistoryUpdate::Hand(hand) => {
+                            state.hands[hand.0][hand.1] = hand.2;
+                        }
                     }
                 }
             }
@@ -227,12 +387,12 @@ pub trait Piece {
 
     fn add_actions(
         &self,
-        actions: &mut Vec<Action>,
+        actions: &mut dyn MoveBuffer,
         board: &Board,
         piece_type: usize,
         from: u32,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) {
         let from_board = BitBoard::from_lsb(from);
 
@@ -250,6 +410,84 @@ pub trait Piece {
                 team,
                 info: NORMAL_MOVE,
                 piece_type,
+                kind: ActionKind::Move,
+            });
+        }
+    }
+
+    /// Adds the actions of the piece on `from` that belong to `stage`, which is one of `Captures`, `Promotions` or `Quiets` (see `Board::generate_stage_into`.)
+    /// Leapers and sliders capture every enemy piece they can move to, so their captures are found directly. Otherwise this adds all of the piece's actions and keeps the ones in `stage`, unless it's overridden.
+    #[allow(clippy::too_many_arguments)]
+    fn add_stage_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        mode: GenerationMode,
+        stage: MoveStage,
+    ) {
+        if stage == MoveStage::Captures && self.get_piece_kind() != PieceKind::Other {
+            let enemies = board.state.all_pieces & !board.state.teams[team as usize];
+            let captures =
+                self.get_moves(board, BitBoard::from_lsb(from), piece_type, team, mode) & enemies;
+            for bit in captures.iter_one_bits(board.state.squares) {
+                actions.push(Action {
+                    from,
+                    to: bit,
+                    team,
+                    info: NORMAL_MOVE,
+                    piece_type,
+                    kind: ActionKind::Move,
+                });
+            }
+            return;
+        }
+
+        let mut piece_actions = MoveList::new();
+        self.add_actions(&mut piece_actions, board, piece_type, from, team, mode);
+        for action in piece_actions.iter() {
+            if self.get_stage(board, action) == stage {
+                actions.push(*action);
+            }
+        }
+    }
+
+    /// Adds the placements of this piece type (see `ActionKind::Place`), which don't come from any piece on the board.
+    /// This is called once per piece type, and most pieces can't be placed.
+    #[allow(unused_variables)]
+    fn add_placements(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+    }
+
+    /// Adds the drops of this piece type, which is only called when `team` has one in hand.
+    /// Pieces can be dropped onto any empty square by default.
+    #[allow(unused_variables)]
+    fn add_drops(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        let empty = !board.state.all_pieces & BitBoard::starting_at_lsb(0, board.state.squares);
+
+        for bit in empty.iter_one_bits(board.state.squares) {
+            actions.push(Action {
+                from: bit,
+                to: bit,
+                team,
+                info: NORMAL_MOVE,
+                piece_type,
+                kind: ActionKind::Drop,
             });
         }
     }
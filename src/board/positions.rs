@@ -1,7 +1,8 @@
 use super::{
-    actions::{Action, HistoryMove, UndoMoveError},
+    actions::{Action, ActionKind, HistoryMove, UndoMoveError},
     edges::Edges,
     game::Game,
+    moves::GenerationMode,
     pieces::Piece,
     Board,
 };
@@ -39,16 +40,18 @@ impl<'a> Board<'a> {
         Ok(col + (self.state.cols * row))
     }
 
+    /// Passes are written as `pass`, and every other action is written by its piece (see `Piece::encode_action`.)
     pub fn encode_action(&self, action: &Action) -> String {
-        return format!(
-            "{}{}{}",
-            self.encode_position(action.from),
-            self.encode_position(action.to),
-            self.game.pieces[action.piece_type].format_info(self, action.info)
-        );
+        if action.kind == ActionKind::Pass {
+            return "pass".to_string();
+        }
+
+        self.game.pieces[action.piece_type].encode_action(self, action)
     }
 
-    pub fn decode_action(&mut self, action: &str, mode: u32) -> Option<Action> {
+    /// Passes can also be decoded from UCI's null move, `0000`.
+    pub fn decode_action(&mut self, action: &str, mode: GenerationMode) -> Option<Action> {
+        let action = if action == "0000" { "pass" } else { action };
         self.generate_legal_moves(mode)
             .iter()
             .find(|el| self.encode_action(el) == action)
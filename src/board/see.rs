@@ -0,0 +1,239 @@
+use arrayvec::ArrayVec;
+
+use super::{
+    actions::{Action, ActionKind},
+    moves::GenerationMode,
+    BitBoard, Board, PieceType,
+};
+
+/// The most captures an exchange is followed for, which is far more than a square is attacked by in any normal position.
+const MAX_EXCHANGES: usize = 32;
+
+impl<'a> Board<'a> {
+    /// Whether `action` comes out at least `threshold` ahead once every piece that can recapture on its `to` square has (see `get_see_value`.)
+    pub fn see(&mut self, action: &Action, threshold: i32) -> bool {
+        self.get_see_value(action) >= threshold
+    }
+
+    /// Static Exchange Evaluation: how much `action` wins (or loses, if negative) by the values in `Game::piece_values`, if both sides keep recapturing on its `to` square with their least valuable attacker for as long as it pays off.
+    /// Pieces behind the attackers (eg. a rook behind another rook) join in as the pieces in front of them are used up, which is found from the ray of every piece's `AttackLookup` that goes through the square a piece left.
+    ///
+    /// Promotions also win the difference between the piece they promote into and the piece they started as.
+    /// Anything besides a move is worth `0`. Legality isn't checked, so a pinned piece can still recapture.
+    pub fn get_see_value(&mut self, action: &Action) -> i32 {
+        if action.kind != ActionKind::Move {
+            return 0;
+        }
+
+        let values = &self.game.piece_values;
+        let from = BitBoard::from_lsb(action.from);
+        let to = BitBoard::from_lsb(action.to);
+
+        let captured = match self.get_piece_type(to) {
+            Some(piece_type) if (to & self.state.teams[action.team as usize]).is_empty() => {
+                values[piece_type]
+            }
+            // Captures that don't land on the captured piece (like en passant) take a piece of the same type.
+            _ if self.is_capture(action) => values[action.piece_type],
+            _ => 0,
+        };
+
+        let mut attackers = BitBoard::new();
+        for team in 0..self.state.teams.len() as u32 {
+            attackers |= self.attackers_to(action.to, team);
+        }
+
+        let (mut on_square, promotion_gain) =
+            match self.game.pieces[action.piece_type].get_promotion(self, action) {
+                Some(promotion) => (
+                    values[promotion],
+                    values[promotion] - values[action.piece_type],
+                ),
+                None => (values[action.piece_type], 0),
+            };
+
+        let all_pieces = self.state.all_pieces;
+        let mut gains: ArrayVec<i32, MAX_EXCHANGES> = ArrayVec::new();
+        gains.push(captured + promotion_gain);
+        let mut removed = action.from;
+        let mut team = action.team;
+        while !gains.is_full() {
+            self.state.all_pieces &= !BitBoard::from_lsb(removed);
+            attackers &= !BitBoard::from_lsb(removed) & !from;
+            attackers |= self.get_xray_attackers(action.to, removed);
+
+            team = self.get_next_team(team);
+            let Some((square, piece_type)) =
+                self.get_least_valuable_attacker(action.to, team, &mut attackers)
+            else {
+                break;
+            };
+
+            gains.push(on_square - gains[gains.len() - 1]);
+            on_square = values[piece_type];
+            removed = square;
+        }
+        self.state.all_pieces = all_pieces;
+
+        // Each side only keeps capturing if it's better than stopping.
+        while gains.len() > 1 {
+            let gain = gains.pop().unwrap();
+            let last = gains.len() - 1;
+            gains[last] = -(-gains[last]).max(gain);
+        }
+        gains[0]
+    }
+
+    fn get_piece_type(&self, square: BitBoard) -> Option<PieceType> {
+        self.state
+            .pieces
+            .iter()
+            .position(|pieces| (*pieces & square).is_set())
+    }
+
+    fn attacks_square(&self, from: u32, target: u32) -> bool {
+        let from_board = BitBoard::from_lsb(from);
+        let target = BitBoard::from_lsb(target);
+        let Some(team) = self
+            .state
+            .teams
+            .iter()
+            .position(|team| (*team & from_board).is_set())
+        else {
+            return false;
+        };
+        let Some(piece_type) = self.get_piece_type(from_board) else {
+            return false;
+        };
+
+        let attacks = self.game.pieces[piece_type].can_move_mask(
+            self,
+            from_board,
+            from,
+            piece_type,
+            team as u32,
+            GenerationMode::Attacks,
+            target,
+        );
+        (attacks & target).is_set()
+    }
+
+    /// The pieces that attack `target` now that the piece on `removed` has left the board, which were hidden behind it.
+    fn get_xray_attackers(&self, target: u32, removed: u32) -> BitBoard {
+        let removed = BitBoard::from_lsb(removed);
+        let mut xrays = BitBoard::new();
+        for lookup in &self.attack_lookup {
+            let Some(directions) = lookup.directions.get(target as usize) else {
+                continue;
+            };
+
+            for ray in directions {
+                if (*ray & removed).is_empty() {
+                    continue;
+                }
+
+                let behind = *ray & self.state.all_pieces;
+                if behind.is_empty() {
+                    continue;
+                }
+
+                let square = if BitBoard::from_lsb(target) < behind {
+                    behind.bitscan_forward()
+                } else {
+                    behind.bitscan_reverse()
+                };
+                if self.attacks_square(square, target) {
+                    xrays |= BitBoard::from_lsb(square);
+                }
+            }
+        }
+        xrays
+    }
+
+    /// Takes `team`'s least valuable piece out of `attackers`, skipping any that stopped attacking `target` (eg. a xiangqi cannon that lost its screen.)
+    fn get_least_valuable_attacker(
+        &self,
+        target: u32,
+        team: u32,
+        attackers: &mut BitBoard,
+    ) -> Option<(u32, PieceType)> {
+        loop {
+            let candidates = *attackers & self.state.teams[team as usize] & self.state.all_pieces;
+            let (piece_type, pieces) = self
+                .state
+                .pieces
+                .iter()
+                .map(|pieces| *pieces & candidates)
+                .enumerate()
+                .filter(|(_, pieces)| pieces.is_set())
+                .min_by_key(|(piece_type, _)| self.game.piece_values[*piece_type])?;
+
+            let square = pieces.bitscan_forward();
+            if self.attacks_square(square, target) {
+                return Some((square, piece_type));
+            }
+            *attackers &= !BitBoard::from_lsb(square);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{moves::GenerationMode, Board},
+        games::chess::{Capablanca, Chess},
+    };
+
+    fn assert_see(board: &mut Board, action: &str, value: i32) {
+        let action = board.decode_action(action, GenerationMode::Normal).unwrap();
+        assert_eq!(board.get_see_value(&action), value, "{}", board.to_fen());
+        assert!(board.see(&action, value));
+        assert!(!board.see(&action, value + 1));
+    }
+
+    #[test]
+    fn static_exchange() {
+        let chess = Chess::create();
+        let capablanca = Capablanca::create();
+
+        // The pawn on e5 is free, until the pawn on d6 defends it.
+        let mut board = Board::new(&chess, (8, 8), "6k1/8/8/4p3/8/8/8/4R1K1 w - - 0 1");
+        assert_see(&mut board, "e1e5", 100);
+        board = Board::new(&chess, (8, 8), "6k1/8/3p4/4p3/8/8/8/4R1K1 w - - 0 1");
+        assert_see(&mut board, "e1e5", -400);
+
+        // The rook on e1 backs up the rook on e2, so white wins the pawn after Rxe5 Rxe5 Rxe5.
+        board = Board::new(&chess, (8, 8), "6k1/4r3/8/4p3/8/8/4R3/4R1K1 w - - 0 1");
+        assert_see(&mut board, "e2e5", 100);
+        // Without it, white would lose the rook.
+        board = Board::new(&chess, (8, 8), "6k1/4r3/8/4p3/8/8/4R3/6K1 w - - 0 1");
+        assert_see(&mut board, "e2e5", -400);
+
+        // Quiet moves are only worth what they lose: the knight on d5 can be taken by the pawn on e6.
+        board = Board::new(&chess, (8, 8), "6k1/8/4p3/8/8/2N5/8/6K1 w - - 0 1");
+        assert_see(&mut board, "c3d5", -300);
+
+        // An en passant capture takes a pawn, even though there's nothing on d6.
+        board = Board::new(&chess, (8, 8), "6k1/8/8/3pP3/8/8/8/6K1 w - d6 0 1");
+        assert_see(&mut board, "e5d6", 100);
+
+        // Promoting wins the difference between a queen and a pawn, unless the rook on d8 takes the queen back.
+        board = Board::new(&chess, (8, 8), "6k1/4P3/8/8/8/8/8/6K1 w - - 0 1");
+        assert_see(&mut board, "e7e8q", 800);
+        board = Board::new(&chess, (8, 8), "3r2k1/4P3/8/8/8/8/8/6K1 w - - 0 1");
+        assert_see(&mut board, "e7e8q", -100);
+        assert_see(&mut board, "e7d8q", 1300);
+
+        // The archbishop takes a knight defended by a pawn.
+        board = Board::new(&capablanca, (8, 10), "k9/10/3p6/4n5/10/5A4/10/9K w - - 0 1");
+        assert_see(&mut board, "f3e5", -500);
+
+        // The chancellor on e1 backs up the rook on e2 along the file.
+        board = Board::new(
+            &capablanca,
+            (8, 10),
+            "k9/4r5/4p5/10/10/10/4R5/4C4K w - - 0 1",
+        );
+        assert_see(&mut board, "e2e6", 100);
+    }
+}
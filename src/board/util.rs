@@ -1,17 +1,25 @@
+use std::{ops::Index, sync::Arc};
+
 use arrayvec::ArrayVec;
 
 use crate::bitset::BitSet;
 
 use super::{
-    actions::{Action, HistoryMove, UndoMoveError},
+    actions::{Action, ActionKind, HistoryMove, HistoryState, UndoMoveError},
+    attacks::AttackMaps,
     edges::{generate_edge_list, Edges},
-    game::Game,
+    game::{Game, GameResults},
+    magics::SliderMagics,
+    moves::{GenerationMode, MoveBuffer, MoveList, MoveStage},
     pieces::Piece,
 };
 
 pub type BitBoard = BitSet<1>;
 pub type PieceType = usize;
 
+/// Extra game-specific state that lives alongside the bitboards, and gets restored when moves are undone.
+pub type CustomState = ArrayVec<u32, 16>;
+
 /// I doubt anyone would be practically creating boards of 4,294,967,296 x 4,294,967,296.
 /// However, storing these as u32s makes it much easier to interface the bitboards with (particularly, shifting bits with them.)
 pub type Rows = u32;
@@ -42,6 +50,15 @@ pub struct BoardState {
     pub cols: Cols,
     pub squares: u32,
 
+    /// Hands are the pieces each team can drop onto the board, indexed by team and then by piece type. Games without drops leave every count at zero.
+    pub hands: Vec<Vec<u32>>,
+
+    /// Custom State is any extra state a game needs to track (see `CustomStateUpdate`.) Most games leave this empty.
+    pub custom: CustomState,
+
+    /// Attack Maps keep track of the squares each team attacks as moves are made and undone, if they've been enabled (see `Board::enable_attack_maps`.)
+    pub attack_maps: Option<AttackMaps>,
+
     pub turn_lookup: ArrayVec<u32, 16>,
     pub team_lookup: ArrayVec<u32, 16>,
     pub turn_reverse_lookup: ArrayVec<u32, 16>,
@@ -52,6 +69,34 @@ impl BoardState {
     pub fn get_piece_team_board(&self, piece: usize, team: usize) -> BitBoard {
         self.pieces[piece] & self.teams[team]
     }
+
+    /// Moves on to the next turn, and to the next team once the moving team has taken all of its turns.
+    pub fn update_turns(&mut self) {
+        self.turns += 1;
+        self.current_turn = self.turn_lookup[self.current_turn as usize];
+        if self.current_turn == 0 {
+            self.sub_moves += 1;
+
+            if self.moving_team == 0 {
+                self.full_moves += 1;
+            }
+            self.moving_team = self.team_lookup[self.moving_team as usize];
+        };
+    }
+
+    /// Goes back to the previous turn, reversing `update_turns`.
+    pub fn undo_turns(&mut self) {
+        self.turns -= 1;
+        self.current_turn = self.turn_reverse_lookup[self.current_turn as usize];
+        if self.current_turn == self.turn_lookup.len() as u32 - 1 {
+            self.moving_team = self.team_reverse_lookup[self.moving_team as usize];
+            self.sub_moves -= 1;
+
+            if self.moving_team == 0 {
+                self.full_moves -= 1;
+            }
+        }
+    }
 }
 
 pub type AttackDirections = Vec<BitBoard>;
@@ -61,14 +106,44 @@ pub type AttackDirections = Vec<BitBoard>;
 /// It stores an `AttackDirections` (alias for `Vec<BitBoard>`).
 ///     For pieces that always move the same way (like Delta Pieces), only the first slot of this AttackDirections is used, because there's no directions.
 ///     For slider pieces, there are different indexes for specific ray directions of it.
+///
+/// With the `magics` feature, sliders also get magic bitboards for their rays (see `SliderMagics`), so that their attacks can be found without any bitscans.
+#[derive(Debug, Clone, Default)]
+pub struct AttackLookup {
+    pub directions: Vec<AttackDirections>,
+    pub magics: Vec<Arc<SliderMagics>>,
+}
 
-pub type AttackLookup = Vec<AttackDirections>;
+impl AttackLookup {
+    /// Gets every square a slider on `square` attacks, up to (and including) the first square in `blockers` in each direction, if it has magics for that square.
+    pub fn get_slider_attacks(&self, square: usize, blockers: BitBoard) -> Option<BitBoard> {
+        if self.magics.is_empty() {
+            return None;
+        }
+
+        let mut attacks = BitBoard::new();
+        for magics in &self.magics {
+            attacks |= magics.get_attacks(square, blockers)?;
+        }
+        Some(attacks)
+    }
+}
+
+impl Index<usize> for AttackLookup {
+    type Output = AttackDirections;
+
+    fn index(&self, square: usize) -> &AttackDirections {
+        &self.directions[square]
+    }
+}
 
 pub struct Board<'a> {
     pub state: BoardState,
     pub game: &'a Game,
     pub attack_lookup: Vec<AttackLookup>,
     pub history: ArrayVec<HistoryMove, 2048>,
+    /// The custom state before each move in `history` was made, for games that use custom state.
+    pub custom_history: Vec<CustomState>,
 }
 
 fn generate_forward_lookup(count: u32) -> ArrayVec<u32, 16> {
@@ -114,6 +189,7 @@ impl<'a> Board<'a> {
             attack_lookup: vec![],
             game,
             history: ArrayVec::new(),
+            custom_history: Vec::new(),
             state: BoardState {
                 all_pieces: BitBoard::new(),
                 first_move: BitBoard::new(),
@@ -128,6 +204,14 @@ impl<'a> Board<'a> {
                 full_moves: 0,
                 sub_moves: 0,
                 turns: 0,
+                hands: (0..game.teams)
+                    .map(|_| vec![0; game.pieces.len()])
+                    .collect::<Vec<_>>(),
+                custom: match &game.custom_state {
+                    Some(custom_state) => custom_state.initial(),
+                    None => CustomState::new(),
+                },
+                attack_maps: None,
                 team_lookup,
                 team_reverse_lookup,
                 turn_lookup,
@@ -140,7 +224,7 @@ impl<'a> Board<'a> {
         board
     }
 
-    pub fn get_move_mask(&self, team: u32, mode: u32) -> BitBoard {
+    pub fn get_move_mask(&self, team: u32, mode: GenerationMode) -> BitBoard {
         let board_len = self.state.squares;
         let mut bitboard = BitBoard::new();
 
@@ -156,10 +240,28 @@ impl<'a> Board<'a> {
         bitboard
     }
 
-    pub fn can_move(&self, team: u32, target: BitBoard, mode: u32) -> bool {
+    /// Starts keeping track of the squares each team attacks (see `AttackMaps`), which `get_attacks` then reads from instead of generating them.
+    /// The maps are built from the current position, so this should be called again if the position is changed without making moves.
+    pub fn enable_attack_maps(&mut self) {
+        self.state.attack_maps = Some(AttackMaps::new(self));
+    }
+
+    /// Every square that `team` attacks, from the attack maps when they're enabled.
+    pub fn get_attacks(&self, team: u32) -> BitBoard {
+        match &self.state.attack_maps {
+            Some(attack_maps) => attack_maps.attacked_by(team),
+            None => self.get_move_mask(team, GenerationMode::Attacks),
+        }
+    }
+
+    /// Whether any of `team`'s pieces can move onto (or attack, depending on `mode`) any square of `target`.
+    pub fn can_move(&self, team: u32, target: BitBoard, mode: GenerationMode) -> bool {
+        if let (GenerationMode::Attacks, Some(attack_maps)) = (mode, &self.state.attack_maps) {
+            return (attack_maps.attacked_by(team) & target).is_set();
+        }
+
         let board_len = self.state.squares;
 
-        let team = self.state.moving_team;
         let mut mask: BitBoard = BitBoard::new();
 
         for (ind, board) in self.state.pieces.iter().enumerate() {
@@ -182,10 +284,55 @@ impl<'a> Board<'a> {
         (mask & target).is_set()
     }
 
-    pub fn generate_moves(&self, mode: u32) -> Vec<Action> {
-        let board_len = self.state.squares;
-        let mut actions: Vec<Action> = Vec::with_capacity(board_len as usize);
+    /// Every one of `team`'s pieces that attacks `square`.
+    pub fn attackers_to(&self, square: u32, team: u32) -> BitBoard {
+        if let Some(attack_maps) = &self.state.attack_maps {
+            return attack_maps.attackers_to(square) & self.state.teams[team as usize];
+        }
+
+        let target = BitBoard::from_lsb(square);
+        let mut attackers = BitBoard::new();
+        for (ind, board) in self.state.pieces.iter().enumerate() {
+            let board = *board & self.state.teams[team as usize];
+            let piece = &self.game.pieces[ind];
 
+            for bit in board.iter_one_bits(self.state.squares) {
+                let from = BitBoard::from_lsb(bit);
+                let attacks = piece.can_move_mask(
+                    self,
+                    from,
+                    bit,
+                    ind,
+                    team,
+                    GenerationMode::Attacks,
+                    target,
+                );
+                if (attacks & target).is_set() {
+                    attackers |= from;
+                }
+            }
+        }
+
+        attackers
+    }
+
+    /// Whether any of `team`'s pieces attack `square`.
+    pub fn is_attacked(&self, square: u32, team: u32) -> bool {
+        match &self.state.attack_maps {
+            Some(attack_maps) => attack_maps.attacker_count(square, team) > 0,
+            None => self.can_move(team, BitBoard::from_lsb(square), GenerationMode::Attacks),
+        }
+    }
+
+    pub fn generate_moves(&self, mode: GenerationMode) -> Vec<Action> {
+        let mut actions: Vec<Action> = Vec::with_capacity(self.state.squares as usize);
+        self.generate_moves_into(&mut actions, mode);
+        actions
+    }
+
+    /// Adds the moves of the team to move onto `actions`, which can be kept around (or be a `MoveList` on the stack) to avoid allocating.
+    pub fn generate_moves_into(&self, actions: &mut impl MoveBuffer, mode: GenerationMode) {
+        let board_len = self.state.squares;
         let team = self.state.moving_team;
 
         for (ind, board) in self.state.pieces.iter().enumerate() {
@@ -193,25 +340,140 @@ impl<'a> Board<'a> {
             let piece = &self.game.pieces[ind];
 
             for bit in board.iter_one_bits(board_len as u32) {
-                piece.add_actions(&mut actions, self, ind, bit, team, mode);
+                piece.add_actions(actions, self, ind, bit, team, mode);
             }
-        }
 
-        actions
+            piece.add_placements(actions, self, ind, team, mode);
+            if self.state.hands[team as usize][ind] > 0 {
+                piece.add_drops(actions, self, ind, team, mode);
+            }
+        }
     }
 
     /*
         Don't use when writing an engine directly; use `generate_moves` and `move_restrictions.is_legal` to avoid extra legality checks during pruning.
     */
-    pub fn generate_legal_moves(&mut self, mode: u32) -> Vec<Action> {
-        let moves = self.generate_moves(mode);
-        let mut legal_moves = Vec::with_capacity(moves.len());
-        for action in moves {
-            if self.game.move_restrictions.is_legal(self, &action) {
-                legal_moves.push(action);
+    pub fn generate_legal_moves(&mut self, mode: GenerationMode) -> Vec<Action> {
+        let mut legal_moves = Vec::with_capacity(self.state.squares as usize);
+        self.generate_legal_moves_into(&mut legal_moves, mode);
+        legal_moves
+    }
+
+    /// Adds the legal moves of the team to move onto `legal_moves`, like `generate_moves_into`.
+    /// `filter_moves` looks at every move in `legal_moves`, so it should start out empty.
+    pub fn generate_legal_moves_into(
+        &mut self,
+        legal_moves: &mut impl MoveBuffer,
+        mode: GenerationMode,
+    ) {
+        let move_restrictions = &self.game.move_restrictions;
+        self.generate_moves_into(legal_moves, mode);
+        move_restrictions.retain_legal_moves(self, legal_moves);
+        move_restrictions.filter_moves(self, legal_moves);
+    }
+
+    /// Adds the legal moves of the team to move that belong to `stage` onto `actions` (see `MovePicker`.)
+    /// Games with a move filter (see `MoveRestrictions::has_move_filter`) generate every legal move and keep the ones in `stage`, since their filter has to see all of them.
+    pub fn generate_stage_into(
+        &mut self,
+        actions: &mut impl MoveBuffer,
+        stage: MoveStage,
+        mode: GenerationMode,
+    ) {
+        let move_restrictions = &self.game.move_restrictions;
+        match stage {
+            MoveStage::Evasions => {
+                if move_restrictions.is_in_check(self) {
+                    self.generate_legal_moves_into(actions, mode);
+                }
+            }
+            MoveStage::Legal => self.generate_legal_moves_into(actions, mode),
+            MoveStage::Checks => self.generate_quiets_into(actions, &mut MoveList::new(), mode),
+            MoveStage::Quiets => self.generate_quiets_into(&mut MoveList::new(), actions, mode),
+            stage if move_restrictions.has_move_filter() => {
+                let mut moves = MoveList::new();
+                self.generate_legal_moves_into(&mut moves, mode);
+                for action in moves.iter() {
+                    if self.game.pieces[action.piece_type].get_stage(self, action) == stage {
+                        actions.push(*action);
+                    }
+                }
             }
+            stage => self.generate_piece_stage_into(actions, stage, mode),
         }
-        legal_moves
+    }
+
+    /// Adds the legal quiet moves of the team to move onto `checks` if they give check, and onto `quiets` otherwise.
+    /// Both stages come from the same quiet moves, so generating them together only has to find those (and whether each one gives check) once.
+    pub fn generate_quiets_into(
+        &mut self,
+        checks: &mut impl MoveBuffer,
+        quiets: &mut impl MoveBuffer,
+        mode: GenerationMode,
+    ) {
+        let mut moves = MoveList::new();
+        if self.game.move_restrictions.has_move_filter() {
+            self.generate_legal_moves_into(&mut moves, mode);
+            moves.retain(&mut |action| {
+                self.game.pieces[action.piece_type].get_stage(self, action) == MoveStage::Quiets
+            });
+        } else {
+            self.generate_piece_stage_into(&mut moves, MoveStage::Quiets, mode);
+        }
+
+        for action in moves.iter() {
+            if self.game.move_restrictions.gives_check(self, action) {
+                checks.push(*action);
+            } else {
+                quiets.push(*action);
+            }
+        }
+    }
+
+    /// Adds the legal moves that pieces put in `stage` (one of `Captures`, `Promotions` or `Quiets`, see `Piece::get_stage`), without running the move filter.
+    fn generate_piece_stage_into(
+        &mut self,
+        actions: &mut impl MoveBuffer,
+        stage: MoveStage,
+        mode: GenerationMode,
+    ) {
+        let mut moves = MoveList::new();
+        let team = self.state.moving_team;
+        for (ind, board) in self.state.pieces.iter().enumerate() {
+            let board = *board & self.state.teams[team as usize];
+            let piece = &self.game.pieces[ind];
+
+            for bit in board.iter_one_bits(self.state.squares) {
+                piece.add_stage_actions(&mut moves, self, ind, bit, team, mode, stage);
+            }
+
+            let mut placements = MoveList::new();
+            piece.add_placements(&mut placements, self, ind, team, mode);
+            if self.state.hands[team as usize][ind] > 0 {
+                piece.add_drops(&mut placements, self, ind, team, mode);
+            }
+            for action in placements.iter() {
+                if piece.get_stage(self, action) == stage {
+                    moves.push(*action);
+                }
+            }
+        }
+
+        self.game
+            .move_restrictions
+            .retain_legal_moves(self, &mut moves);
+        for action in moves.iter() {
+            actions.push(*action);
+        }
+    }
+
+    pub fn is_capture(&self, action: &Action) -> bool {
+        self.game.pieces[action.piece_type].is_capture(self, action)
+    }
+
+    pub fn resolve(&mut self) -> GameResults {
+        let legal_moves = self.generate_legal_moves(GenerationMode::Normal);
+        self.game.resolution.resolve(self, &legal_moves)
     }
 
     pub fn get_next_team(&self, mut team: u32) -> u32 {
@@ -234,20 +496,61 @@ impl<'a> Board<'a> {
         }
     }
 
+    /// Passes are made here directly, and every other action is made by the piece that's moving (or being placed.)
     pub fn make_move(&mut self, action: &Action) {
-        self.game.pieces[action.piece_type].make_move(self, action);
+        if self.game.custom_state.is_some() {
+            self.custom_history.push(self.state.custom.clone());
+        }
+        self.make_action(action);
+
+        if let Some(mut attack_maps) = self.state.attack_maps.take() {
+            attack_maps.make_move(self);
+            self.state.attack_maps = Some(attack_maps);
+        }
+
+        // Custom state is updated last, so that it can use the attack maps of the new position.
+        if let Some(custom_state) = &self.game.custom_state {
+            custom_state.update(self, action);
+        }
+    }
+
+    fn make_action(&mut self, action: &Action) {
+        match action.kind {
+            ActionKind::Pass => self.make_pass(action),
+            _ => self.game.pieces[action.piece_type].make_move(self, action),
+        }
+    }
+
+    /// Passes the turn without changing the board, for games that require passing (eg. Go) or for engines making null moves.
+    pub fn make_pass(&mut self, action: &Action) {
+        self.history.push(HistoryMove {
+            action: *action,
+            state: HistoryState::None,
+        });
+        self.state.update_turns();
     }
 
     #[inline(never)]
     pub fn undo_move(&mut self) -> Result<(), UndoMoveError> {
         match self.history.last() {
             Some(history_move) => {
-                self.game.pieces[history_move.action.piece_type].undo_move(
-                    &mut self.state,
-                    self.game,
-                    history_move,
-                );
+                if history_move.action.kind == ActionKind::Pass {
+                    self.state.undo_turns();
+                } else {
+                    self.game.pieces[history_move.action.piece_type].undo_move(
+                        &mut self.state,
+                        self.game,
+                        history_move,
+                    );
+                }
                 self.history.pop();
+                if let Some(custom) = self.custom_history.pop() {
+                    self.state.custom = custom;
+                }
+                if let Some(mut attack_maps) = self.state.attack_maps.take() {
+                    attack_maps.undo_move(self);
+                    self.state.attack_maps = Some(attack_maps);
+                }
                 Ok(())
             }
             None => Err(UndoMoveError::NoHistoryMoves),
@@ -0,0 +1,164 @@
+use crate::{
+    board::{
+        actions::{
+            Action, ActionKind, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard,
+            PreviousBoard,
+        },
+        moves::{GenerationMode, MoveBuffer},
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::{get_blocked_ray, get_ray_attacks, QueenPiece},
+};
+
+pub const AMAZON_PIECE_TYPE: usize = 0;
+pub const ARROW_PIECE_TYPE: usize = 1;
+
+/// Amazons move like queens (without capturing), and then shoot an arrow that also moves like a queen from where they landed.
+/// Both parts make up a single action, with the square the arrow lands on stored in its `info`.
+pub struct AmazonPiece;
+
+impl Piece for AmazonPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('q')
+    }
+
+    fn format_info(&self, board: &Board, info: usize) -> String {
+        format!("/{}", board.encode_position(info as u32))
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        QueenPiece.generate_lookup_moves(board, from)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the amazon attack lookup.");
+        let mut moves = BitBoard::new();
+
+        let from_bit = from.bitscan_forward() as usize;
+        for dir in 0..8 {
+            moves |= get_ray_attacks(board, from, from_bit, dir, lookup);
+        }
+
+        moves & !board.state.all_pieces
+    }
+
+    #[allow(unused_variables)]
+    fn is_capture(&self, board: &Board, action: &Action) -> bool {
+        false
+    }
+
+    fn add_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the amazon attack lookup.");
+        let from_board = BitBoard::from_lsb(from);
+
+        // The amazon has left its square by the time the arrow is shot, so arrows can fly through (or land on) it.
+        let blockers = board.state.all_pieces & !from_board;
+
+        let moves = self.get_moves(board, from_board, piece_type, team, mode);
+        for to in moves.iter_one_bits(board.state.squares) {
+            let to_board = BitBoard::from_lsb(to);
+
+            let mut arrows = BitBoard::new();
+            for dir in 0..8 {
+                arrows |= get_blocked_ray(to_board, to as usize, dir, lookup, blockers);
+            }
+            arrows &= !blockers;
+
+            for arrow in arrows.iter_one_bits(board.state.squares) {
+                actions.push(Action {
+                    from,
+                    to,
+                    team,
+                    piece_type,
+                    info: arrow as usize,
+                    kind: ActionKind::Move,
+                });
+            }
+        }
+    }
+
+    fn make_move(&self, board: &mut Board, action: &Action) {
+        let color = action.team as usize;
+        let from = BitBoard::from_lsb(action.from);
+        let to = BitBoard::from_lsb(action.to);
+        let arrow = BitBoard::from_lsb(action.info as u32);
+
+        board.history.push(HistoryMove {
+            action: *action,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        action.piece_type,
+                        board.state.pieces[action.piece_type],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        ARROW_PIECE_TYPE,
+                        board.state.pieces[ARROW_PIECE_TYPE],
+                    )),
+                ],
+            },
+        });
+
+        board.state.teams[color] = (board.state.teams[color] & !from) | to | arrow;
+        board.state.pieces[action.piece_type] =
+            (board.state.pieces[action.piece_type] & !from) | to;
+        board.state.pieces[ARROW_PIECE_TYPE] |= arrow;
+        board.state.all_pieces = (board.state.all_pieces & !from) | to | arrow;
+
+        self.update_turns(board);
+    }
+}
+
+/// Arrows never move, and only block amazons (and other arrows.)
+/// They belong to the team that shot them, but that doesn't affect the game.
+pub struct ArrowPiece;
+
+impl Piece for ArrowPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('x')
+    }
+
+    fn can_lookup(&self) -> bool {
+        false
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        BitBoard::new()
+    }
+}
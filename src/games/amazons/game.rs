@@ -0,0 +1,52 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenOptions, FenState, FenTeamArgument, NoPostProcess},
+    game::{Game, GameResults, NoRestrictions, Resolution},
+    Board,
+};
+
+use super::{AmazonPiece, ArrowPiece};
+
+/// A team loses once none of its amazons can move (and shoot.)
+pub struct AmazonsResolution;
+impl Resolution for AmazonsResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        if legal_moves.is_empty() {
+            GameResults::Win(board.get_next_team(board.state.moving_team))
+        } else {
+            GameResults::Ongoing
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(AmazonsResolution)
+    }
+}
+
+/// The Game of the Amazons is played on a 10x10 board, with four amazons (`q`) per team that leave arrows (`x`) behind them.
+/// Actions are written as the amazon's move followed by where its arrow lands (eg. `d1d7/g7`.)
+///
+/// Its starting position is `3q2q3/10/10/q8q/10/10/Q8Q/10/10/3Q2Q3 w`.
+pub struct Amazons;
+
+impl Amazons {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![&AmazonPiece, &ArrowPiece],
+            piece_values: vec![100, 0],
+            move_restrictions: Box::new(NoRestrictions),
+            resolution: Box::new(AmazonsResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![(
+                    "team to move".to_string(),
+                    Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                )],
+                post_process: Box::new(NoPostProcess),
+            },
+        }
+    }
+}
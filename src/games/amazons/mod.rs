@@ -0,0 +1,63 @@
+mod amazon;
+mod game;
+
+pub use amazon::*;
+pub use game::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::amazons::Amazons,
+    };
+
+    const STARTPOS: &str = "3q2q3/10/10/q8q/10/10/Q8Q/10/10/3Q2Q3 w";
+
+    #[test]
+    fn startpos() {
+        let amazons = Amazons::create();
+        let mut board = Board::new(&amazons, (10, 10), STARTPOS);
+
+        board.assert_perfts([2176, 4307152]);
+    }
+
+    #[test]
+    fn small_boards() {
+        let amazons = Amazons::create();
+        let mut board = Board::new(&amazons, (6, 6), "2q3/5q/6/Q5/6/3Q2 w");
+        board.assert_perfts([430, 149467]);
+
+        board = Board::new(&amazons, (5, 5), "xq1X1/1xXx1/xQx2/2Xxq/1xQX1 w");
+        board.assert_perfts([10, 233, 1030, 11167]);
+    }
+
+    #[test]
+    fn arrows() {
+        let amazons = Amazons::create();
+        let mut board = Board::new(&amazons, (10, 10), STARTPOS);
+
+        // Arrows can be shot back through the square the amazon just left.
+        let action = board
+            .decode_action("d1d7/d1", GenerationMode::Normal)
+            .unwrap();
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "3q2q3/10/10/q2Q5q/10/10/Q8Q/10/10/3X2Q3 b");
+
+        board.undo_move().unwrap();
+        assert_eq!(board.to_fen(), STARTPOS);
+        assert!(board
+            .decode_action("d1d7/d10", GenerationMode::Normal)
+            .is_none());
+    }
+
+    #[test]
+    fn trapped_amazons_lose() {
+        let amazons = Amazons::create();
+        let mut board = Board::new(&amazons, (5, 5), "Qx3/xx3/5/5/4q w");
+
+        assert!(board
+            .generate_legal_moves(GenerationMode::Normal)
+            .is_empty());
+        assert_eq!(board.resolve(), GameResults::Win(1));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenOptions, FenState, FenTeamArgument, NoPostProcess},
+    game::{Game, GameResults, NoRestrictions, Resolution},
+    Board,
+};
+
+use super::BreakthroughPawn;
+
+/// A team wins by reaching the far rank with any of its pawns, or by capturing every enemy pawn.
+pub struct BreakthroughResolution;
+impl Resolution for BreakthroughResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let edges = &board.state.edges[0];
+        if (board.state.teams[0] & edges.top).is_set() {
+            return GameResults::Win(0);
+        }
+        if (board.state.teams[1] & edges.bottom).is_set() {
+            return GameResults::Win(1);
+        }
+
+        if legal_moves.is_empty() {
+            GameResults::Win(board.get_next_team(board.state.moving_team))
+        } else {
+            GameResults::Ongoing
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(BreakthroughResolution)
+    }
+}
+
+/// Breakthrough is played on an 8x8 board, where each team starts with two ranks of pawns and races to get one of them to the other side.
+///
+/// Its starting position is `pppppppp/pppppppp/8/8/8/8/PPPPPPPP/PPPPPPPP w`.
+pub struct Breakthrough;
+
+impl Breakthrough {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![&BreakthroughPawn],
+            piece_values: vec![100],
+            move_restrictions: Box::new(NoRestrictions),
+            resolution: Box::new(BreakthroughResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![(
+                    "team to move".to_string(),
+                    Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                )],
+                post_process: Box::new(NoPostProcess),
+            },
+        }
+    }
+}
@@ -0,0 +1,73 @@
+mod game;
+mod pawn;
+
+pub use game::*;
+pub use pawn::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::breakthrough::Breakthrough,
+    };
+
+    #[test]
+    fn startpos() {
+        let breakthrough = Breakthrough::create();
+        let mut board = Board::new(
+            &breakthrough,
+            (8, 8),
+            "pppppppp/pppppppp/8/8/8/8/PPPPPPPP/PPPPPPPP w",
+        );
+
+        board.assert_perfts([22, 484, 11132, 256036]);
+    }
+
+    #[test]
+    fn midgame() {
+        let breakthrough = Breakthrough::create();
+        let mut board = Board::new(&breakthrough, (8, 8), "8/1pp2p2/3p4/2P1P1p1/8/1P3pP1/8/8 w");
+
+        board.assert_perfts([12, 196, 2083, 31907, 306919]);
+    }
+
+    #[test]
+    fn captures() {
+        let breakthrough = Breakthrough::create();
+        let mut board = Board::new(&breakthrough, (8, 8), "8/8/8/2ppp3/3P4/8/8/8 w");
+
+        // The pawn can't capture straight ahead, so it can only take diagonally.
+        let mut moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        moves.sort();
+        assert_eq!(moves, vec!["d4c5", "d4e5"]);
+    }
+
+    #[test]
+    fn reaching_the_last_rank_wins() {
+        let breakthrough = Breakthrough::create();
+        let mut board = Board::new(&breakthrough, (8, 8), "8/3P4/8/8/8/8/4p3/8 w");
+
+        let action = board.decode_action("d7d8", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+
+        board.undo_move().unwrap();
+        let action = board.decode_action("d7c8", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+
+    #[test]
+    fn capturing_every_pawn_wins() {
+        let breakthrough = Breakthrough::create();
+        let mut board = Board::new(&breakthrough, (8, 8), "8/8/8/4p3/3P4/8/8/8 w");
+
+        let action = board.decode_action("d4e5", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+}
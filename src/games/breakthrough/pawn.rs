@@ -0,0 +1,58 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::up,
+};
+
+/// Breakthrough's pawn steps one square straight or diagonally forward, but can only capture diagonally.
+/// Unlike chess pawns, it never moves two squares, and there's no en passant or promotion.
+pub struct BreakthroughPawn;
+
+impl Piece for BreakthroughPawn {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('p')
+    }
+
+    /// The lookup stores each team's straight step, then its diagonal steps (`[team 0 straight, team 0 diagonals, team 1 straight, team 1 diagonals]`).
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let cols = board.state.cols;
+        let edges = &board.state.edges[0];
+
+        let mut lookups = vec![];
+        for (team, last_rank) in [(0, edges.top), (1, edges.bottom)] {
+            let from = from & !last_rank;
+            lookups.push(up(&from, 1, cols, team));
+            lookups.push(
+                up(&(from & !edges.left), 1, cols, team).left(1)
+                    | up(&(from & !edges.right), 1, cols, team).right(1),
+            );
+        }
+        lookups
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the breakthrough pawn attack lookup.");
+        let moves = &lookup[from.bitscan_forward() as usize];
+        let team = team as usize;
+
+        (moves[team * 2] & !board.state.all_pieces)
+            | (moves[team * 2 + 1] & !board.state.teams[team])
+    }
+}
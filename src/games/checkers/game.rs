@@ -0,0 +1,115 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenOptions, FenState, FenTeamArgument, NoPostProcess},
+    game::{ForcedCaptures, Game, GameResults, MoveRestrictions, NoRestrictions, Resolution},
+    moves::MoveBuffer,
+    pieces::Piece,
+    Board,
+};
+
+use super::pieces::{get_capture_count, KingPiece, ManPiece};
+
+/// Captures are mandatory, and out of every capture sequence, the ones capturing the most pieces have to be played.
+pub struct MajorityCaptures;
+impl MoveRestrictions for MajorityCaptures {
+    #[allow(unused_variables)]
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn filter_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        let most_captures = moves
+            .as_slice()
+            .iter()
+            .map(get_capture_count)
+            .max()
+            .unwrap_or(0);
+        moves.retain(&mut |action| get_capture_count(action) == most_captures);
+    }
+
+    fn has_move_filter(&self) -> bool {
+        true
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(MajorityCaptures)
+    }
+}
+
+/// A team loses once it has no moves left, whether it's been blocked in or all of its pieces have been captured.
+pub struct CheckersResolution;
+impl Resolution for CheckersResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        if legal_moves.is_empty() {
+            GameResults::Win(board.get_next_team(board.state.moving_team))
+        } else {
+            GameResults::Ongoing
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(CheckersResolution)
+    }
+}
+
+fn create_fen_options() -> FenOptions {
+    FenOptions {
+        state: FenState { first_moves: false },
+        args: vec![(
+            "team to move".to_string(),
+            Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+        )],
+        post_process: Box::new(NoPostProcess),
+    }
+}
+
+const AMERICAN_MAN: &dyn Piece = &ManPiece {
+    captures_backward: false,
+};
+const AMERICAN_KING: &dyn Piece = &KingPiece { flying: false };
+
+/// American checkers (english draughts) is played on the dark squares of an 8x8 board, with men (`m`) and kings (`k`). Black moves first.
+///
+/// Its starting position is `1m1m1m1m/m1m1m1m1/1m1m1m1m/8/8/M1M1M1M1/1M1M1M1M/M1M1M1M1 b`.
+pub struct Checkers;
+
+impl Checkers {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![AMERICAN_MAN, AMERICAN_KING],
+            piece_values: vec![100, 300],
+            move_restrictions: Box::new(ForcedCaptures(Box::new(NoRestrictions))),
+            resolution: Box::new(CheckersResolution),
+            custom_state: None,
+            fen_options: create_fen_options(),
+        }
+    }
+}
+
+const INTERNATIONAL_MAN: &dyn Piece = &ManPiece {
+    captures_backward: true,
+};
+const INTERNATIONAL_KING: &dyn Piece = &KingPiece { flying: true };
+
+/// International draughts is played on a 10x10 board, where men can also capture backward, kings fly, and the capture sequence taking the most pieces must be played. White moves first.
+///
+/// Its starting position is `1m1m1m1m1m/m1m1m1m1m1/1m1m1m1m1m/m1m1m1m1m1/10/10/1M1M1M1M1M/M1M1M1M1M1/1M1M1M1M1M/M1M1M1M1M1 w`.
+pub struct InternationalDraughts;
+
+impl InternationalDraughts {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![INTERNATIONAL_MAN, INTERNATIONAL_KING],
+            piece_values: vec![100, 300],
+            move_restrictions: Box::new(MajorityCaptures),
+            resolution: Box::new(CheckersResolution),
+            custom_state: None,
+            fen_options: create_fen_options(),
+        }
+    }
+}
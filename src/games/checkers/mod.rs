@@ -0,0 +1,164 @@
+mod game;
+mod notation;
+pub mod pieces;
+
+pub use game::*;
+pub use notation::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, BitBoard, Board},
+        games::checkers::{
+            convert_pdn_fen, decode_pdn, encode_pdn, encode_pdn_path, get_square_number,
+            pieces::get_captured, to_pdn_fen, Checkers, InternationalDraughts,
+        },
+    };
+
+    #[test]
+    fn checkers_startpos() {
+        let checkers = Checkers::create();
+        let mut board = Board::new(
+            &checkers,
+            (8, 8),
+            "1m1m1m1m/m1m1m1m1/1m1m1m1m/8/8/M1M1M1M1/1M1M1M1M/M1M1M1M1 b",
+        );
+
+        board.assert_perfts([7, 49, 302, 1469, 7361, 36768, 179740]);
+    }
+
+    #[test]
+    fn checkers_kings() {
+        let checkers = Checkers::create();
+        let fen = convert_pdn_fen("B:W18,24,27,28,K10,K15:B12,16,20,K22,K25,K29", (8, 8)).unwrap();
+        let mut board = Board::new(&checkers, (8, 8), &fen);
+
+        board.assert_perfts([5, 38, 178, 1378, 5836, 40745]);
+    }
+
+    #[test]
+    fn international_startpos() {
+        let draughts = InternationalDraughts::create();
+        let mut board = Board::new(
+            &draughts,
+            (10, 10),
+            "1m1m1m1m1m/m1m1m1m1m1/1m1m1m1m1m/m1m1m1m1m1/10/10/1M1M1M1M1M/M1M1M1M1M1/1M1M1M1M1M/M1M1M1M1M1 w",
+        );
+
+        board.assert_perfts([9, 81, 658, 4265, 27117]);
+    }
+
+    #[test]
+    fn international_midgame() {
+        let draughts = InternationalDraughts::create();
+        let fen = convert_pdn_fen(
+            "W:W25,27,28,30,32,33,34,35,37,38,K44:B12,13,14,16,18,19,21,23,24,K26",
+            (10, 10),
+        )
+        .unwrap();
+        let mut board = Board::new(&draughts, (10, 10), &fen);
+
+        board.assert_perfts([10, 24, 106, 500, 1885]);
+    }
+
+    #[test]
+    fn majority_capture() {
+        let draughts = InternationalDraughts::create();
+        let fen = convert_pdn_fen("W:WK46,28:B13,14,19,22,23,24,32,33,42", (10, 10)).unwrap();
+        let mut board = Board::new(&draughts, (10, 10), &fen);
+
+        let moves = board.generate_legal_moves(GenerationMode::Normal);
+        assert_eq!(moves.len(), 1);
+
+        board.make_move(&moves[0]);
+        assert_eq!(to_pdn_fen(&board), "B:WK46,48:B13,14,19,22,23,24,33");
+        board.assert_perfts([10, 55, 399, 2431]);
+    }
+
+    #[test]
+    fn multi_jump() {
+        let checkers = Checkers::create();
+        let fen = convert_pdn_fen("B:W9,18,27:B5", (8, 8)).unwrap();
+        let mut board = Board::new(&checkers, (8, 8), &fen);
+
+        // The jump can't stop halfway, and the man is crowned when it lands on the last rank.
+        let moves = board.generate_legal_moves(GenerationMode::Normal);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(encode_pdn(&board, &moves[0]), "5x32");
+        assert_eq!(encode_pdn_path(&board, &moves[0]), "5x14x23x32");
+        assert!(decode_pdn(&mut board, "5x14x23x32").is_some());
+
+        // The action already knows which men it captures, so making it doesn't need to search for them again.
+        let captured = get_captured(&board, &moves[0]);
+        let mut numbers = captured
+            .iter_one_bits(board.state.squares)
+            .map(|square| get_square_number(&board, square).unwrap())
+            .collect::<Vec<_>>();
+        numbers.sort();
+        assert_eq!(numbers, vec![9, 18, 27]);
+
+        board.make_move(&moves[0]);
+        assert_eq!(to_pdn_fen(&board), "W:W:BK32");
+        assert_eq!(board.resolve(), GameResults::Win(1));
+
+        board.undo_move().unwrap();
+        assert_eq!(to_pdn_fen(&board), "B:W9,18,27:B5");
+    }
+
+    #[test]
+    fn capture_path_order() {
+        let checkers = Checkers::create();
+        let mut board = Board::new(&checkers, (8, 8), "8/2K5/1m1m4/8/1m1m4/8/8/8 w");
+
+        // The king jumps around all four men and ends up back where it started, so only the order it landed in tells which way it went.
+        let moves = board.generate_legal_moves(GenerationMode::Normal);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(encode_pdn(&board, &moves[0]), "6x6");
+        assert_eq!(encode_pdn_path(&board, &moves[0]), "6x13x22x15x6");
+
+        board.make_move(&moves[0]);
+        assert_eq!(to_pdn_fen(&board), "B:WK6:B");
+    }
+
+    #[test]
+    fn move_masks() {
+        let checkers = Checkers::create();
+        let draughts = InternationalDraughts::create();
+        let checkers_fen =
+            convert_pdn_fen("B:W18,24,27,28,K10,K15:B12,16,20,K22,K25,K29", (8, 8)).unwrap();
+        let draughts_fen =
+            convert_pdn_fen("W:WK46,28:B13,14,19,22,23,24,32,33,42", (10, 10)).unwrap();
+
+        // Both positions have kings as well as men, and the draughts one has captures.
+        for (game, size, fen) in [
+            (&checkers, (8, 8), checkers_fen.as_str()),
+            (&draughts, (10, 10), draughts_fen.as_str()),
+        ] {
+            let mut board = Board::new(game, size, fen);
+            for team in 0..2 {
+                board.state.moving_team = team;
+                let mut moves = BitBoard::new();
+                for action in board.generate_moves(GenerationMode::Normal).iter() {
+                    moves |= BitBoard::from_lsb(action.to);
+                }
+                assert_eq!(board.get_move_mask(team, GenerationMode::Normal), moves);
+            }
+        }
+    }
+
+    #[test]
+    fn pdn_notation() {
+        let checkers = Checkers::create();
+        let mut board = Board::new(
+            &checkers,
+            (8, 8),
+            "1m1m1m1m/m1m1m1m1/1m1m1m1m/8/8/M1M1M1M1/1M1M1M1M/M1M1M1M1 b",
+        );
+
+        for action in ["11-15", "22-18", "15x22", "25x18", "8-11"] {
+            let decoded = decode_pdn(&mut board, action).expect(&format!("{action} is not legal"));
+            assert_eq!(encode_pdn(&board, &decoded), action);
+            board.make_move(&decoded);
+        }
+    }
+}
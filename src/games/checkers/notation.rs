@@ -0,0 +1,176 @@
+use crate::board::{actions::Action, moves::GenerationMode, Board, Cols, Rows};
+
+use super::pieces::KING_PIECE_TYPE;
+
+/// Only the dark squares are numbered, starting from 1 at the top left and counting left to right, then top to bottom.
+/// The bottom left corner is always a dark square.
+pub fn get_square_number(board: &Board, square: u32) -> Option<u32> {
+    let cols = board.state.cols;
+    let (row, col) = (square / cols, square % cols);
+    if (row + col) % 2 == 0 {
+        return None;
+    }
+
+    Some((square / 2) + 1)
+}
+
+fn get_square(cols: Cols, number: u32) -> u32 {
+    let per_row = cols / 2;
+    let row = (number - 1) / per_row;
+    let col = (2 * ((number - 1) % per_row)) + ((row + 1) % 2);
+    (row * cols) + col
+}
+
+fn get_number(board: &Board, square: u32) -> u32 {
+    get_square_number(board, square).expect("Checkers pieces can only stand on dark squares.")
+}
+
+/// Encodes a capture with every square it lands on (eg. `15x24x31`), which PDN uses whenever the shorter form would be ambiguous.
+pub fn encode_pdn_path(board: &Board, action: &Action) -> String {
+    board.game.pieces[action.piece_type]
+        .get_path(board, action)
+        .iter()
+        .map(|&square| get_number(board, square).to_string())
+        .collect::<Vec<_>>()
+        .join("x")
+}
+
+/// Encodes an action in PDN notation, like `11-15` for moves and `15x24` for captures.
+pub fn encode_pdn(board: &Board, action: &Action) -> String {
+    let from = get_number(board, action.from);
+    let to = get_number(board, action.to);
+
+    match action.info {
+        0 => format!("{from}-{to}"),
+        _ => {
+            let ambiguous = board
+                .generate_moves(GenerationMode::Normal)
+                .iter()
+                .any(|other| {
+                    other.from == action.from && other.to == action.to && other.info != action.info
+                });
+
+            if ambiguous {
+                encode_pdn_path(board, action)
+            } else {
+                format!("{from}x{to}")
+            }
+        }
+    }
+}
+
+/// Finds the legal action described by some PDN notation, accepting the full path of a capture as well.
+pub fn decode_pdn(board: &mut Board, action: &str) -> Option<Action> {
+    board
+        .generate_legal_moves(GenerationMode::Normal)
+        .iter()
+        .find(|el| encode_pdn(board, el) == action || encode_pdn_path(board, el) == action)
+        .copied()
+}
+
+/// Converts a PDN FEN tag (eg. `B:W18,24,K10:B12,16,K22` or `W:W31-50:B1-20`) to a FEN this crate can load.
+pub fn convert_pdn_fen(pdn: &str, (rows, cols): (Rows, Cols)) -> Result<String, String> {
+    let mut squares = vec![None; (rows * cols) as usize];
+    let mut sections = pdn.trim().split(':');
+
+    let team = match sections.next() {
+        Some("W") => "w",
+        Some("B") => "b",
+        _ => return Err(format!("'{pdn}' doesn't start with the team to move.")),
+    };
+
+    for section in sections {
+        let mut chars = section.chars();
+        let is_white = match chars.next() {
+            Some('W') => true,
+            Some('B') => false,
+            _ => {
+                return Err(format!(
+                    "'{section}' isn't a list of white or black pieces."
+                ))
+            }
+        };
+
+        for piece in chars.as_str().split(',').filter(|piece| !piece.is_empty()) {
+            let (is_king, numbers) = match piece.strip_prefix('K') {
+                Some(numbers) => (true, numbers),
+                None => (false, piece),
+            };
+
+            let parse = |number: &str| {
+                number
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|number| *number >= 1 && *number <= (rows * cols) / 2)
+                    .ok_or(format!("'{number}' is not a valid square number."))
+            };
+            let (start, end) = match numbers.split_once('-') {
+                Some((start, end)) => (parse(start)?, parse(end)?),
+                None => (parse(numbers)?, parse(numbers)?),
+            };
+
+            let symbol = match (is_king, is_white) {
+                (true, true) => 'K',
+                (true, false) => 'k',
+                (false, true) => 'M',
+                (false, false) => 'm',
+            };
+            for number in start..=end {
+                squares[get_square(cols, number) as usize] = Some(symbol);
+            }
+        }
+    }
+
+    let mut state = vec![];
+    for row in squares.chunks(cols as usize) {
+        let mut encoded = String::new();
+        let mut empty = 0;
+        for square in row {
+            match square {
+                Some(symbol) => {
+                    if empty > 0 {
+                        encoded.push_str(&empty.to_string());
+                        empty = 0;
+                    }
+                    encoded.push(*symbol);
+                }
+                None => empty += 1,
+            }
+        }
+        if empty > 0 {
+            encoded.push_str(&empty.to_string());
+        }
+        state.push(encoded);
+    }
+
+    Ok(format!("{} {team}", state.join("/")))
+}
+
+/// Encodes the board as a PDN FEN tag, like `W:W31,32,K10:B1,2`.
+pub fn to_pdn_fen(board: &Board) -> String {
+    let team = match board.state.moving_team {
+        0 => "W",
+        _ => "B",
+    };
+
+    let sections = ["W", "B"]
+        .iter()
+        .enumerate()
+        .map(|(color, name)| {
+            let pieces = board.state.teams[color]
+                .iter_one_bits(board.state.squares)
+                .map(|square| {
+                    let number = get_number(board, square);
+                    if board.state.pieces[KING_PIECE_TYPE].has_bit(square) {
+                        format!("K{number}")
+                    } else {
+                        number.to_string()
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("{name}{}", pieces.join(","))
+        })
+        .collect::<Vec<_>>();
+
+    format!("{team}:{}", sections.join(":"))
+}
@@ -0,0 +1,87 @@
+use crate::board::{
+    actions::Action,
+    moves::{GenerationMode, MoveBuffer},
+    pieces::{Piece, PieceSymbol},
+    AttackDirections, BitBoard, Board,
+};
+
+use super::{
+    add_checkers_actions, generate_diagonal_lookups, get_capture_path, get_checkers_moves,
+    make_checkers_move, CheckersMovement, DIAGONALS,
+};
+
+/// Kings move and capture along every diagonal.
+pub struct KingPiece {
+    /// Kings only step one square at a time in american checkers, but fly along whole diagonals in international draughts.
+    pub flying: bool,
+}
+
+impl KingPiece {
+    fn get_movement(&self) -> CheckersMovement {
+        CheckersMovement {
+            directions: &DIAGONALS,
+            capture_directions: &DIAGONALS,
+            flying: self.flying,
+        }
+    }
+}
+
+impl Piece for KingPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('k')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        generate_diagonal_lookups(board, from)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    /// Quiet moves leave their `info` at `0`, while captures keep the squares they capture in it.
+    #[allow(unused_variables)]
+    fn is_capture(&self, board: &Board, action: &Action) -> bool {
+        action.info != 0
+    }
+
+    fn get_path(&self, board: &Board, action: &Action) -> Vec<u32> {
+        get_capture_path(board, action, &self.get_movement())
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        get_checkers_moves(
+            board,
+            piece_type,
+            from.bitscan_forward(),
+            team,
+            &self.get_movement(),
+        )
+    }
+
+    fn make_move(&self, board: &mut Board, action: &Action) {
+        make_checkers_move(board, action, None);
+        self.update_turns(board);
+    }
+
+    #[allow(unused_variables)]
+    fn add_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        add_checkers_actions(actions, board, piece_type, from, team, &self.get_movement());
+    }
+}
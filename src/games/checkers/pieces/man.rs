@@ -0,0 +1,109 @@
+use crate::{
+    board::{
+        actions::Action,
+        moves::{GenerationMode, MoveBuffer},
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::get_rank,
+};
+
+use super::{
+    add_checkers_actions, generate_diagonal_lookups, get_capture_path, get_checkers_moves,
+    get_forward_diagonals, make_checkers_move, CheckersMovement, DIAGONALS, KING_PIECE_TYPE,
+};
+
+/// Men step one square diagonally forward, and are crowned as kings when they finish a move on the last rank.
+pub struct ManPiece {
+    /// Men can only capture forward in american checkers, but can capture backward as well in international draughts.
+    pub captures_backward: bool,
+}
+
+impl ManPiece {
+    fn get_movement(&self, team: u32) -> CheckersMovement {
+        let forward = get_forward_diagonals(team);
+        CheckersMovement {
+            directions: forward,
+            capture_directions: if self.captures_backward {
+                &DIAGONALS
+            } else {
+                forward
+            },
+            flying: false,
+        }
+    }
+}
+
+impl Piece for ManPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('m')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        generate_diagonal_lookups(board, from)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    /// Quiet moves leave their `info` at `0`, while captures keep the squares they capture in it.
+    #[allow(unused_variables)]
+    fn is_capture(&self, board: &Board, action: &Action) -> bool {
+        action.info != 0
+    }
+
+    fn get_path(&self, board: &Board, action: &Action) -> Vec<u32> {
+        get_capture_path(board, action, &self.get_movement(action.team))
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        get_checkers_moves(
+            board,
+            piece_type,
+            from.bitscan_forward(),
+            team,
+            &self.get_movement(team),
+        )
+    }
+
+    fn make_move(&self, board: &mut Board, action: &Action) {
+        let last_rank = get_rank(board, board.state.rows, action.team);
+        let promotion = if last_rank.has_bit(action.to) {
+            Some(KING_PIECE_TYPE)
+        } else {
+            None
+        };
+
+        make_checkers_move(board, action, promotion);
+        self.update_turns(board);
+    }
+
+    #[allow(unused_variables)]
+    fn add_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        add_checkers_actions(
+            actions,
+            board,
+            piece_type,
+            from,
+            team,
+            &self.get_movement(team),
+        );
+    }
+}
@@ -0,0 +1,375 @@
+mod king;
+mod man;
+
+pub use king::*;
+pub use man::*;
+
+use arrayvec::ArrayVec;
+
+use crate::{
+    board::{
+        actions::{
+            Action, ActionInfo, ActionKind, HistoryMove, HistoryState, HistoryUpdate,
+            IndexedPreviousBoard, PreviousBoard,
+        },
+        moves::MoveBuffer,
+        pieces::Piece,
+        AttackLookup, BitBoard, Board, PieceType,
+    },
+    games::chess::pieces::BishopPiece,
+};
+
+pub const MAN_PIECE_TYPE: PieceType = 0;
+pub const KING_PIECE_TYPE: PieceType = 1;
+
+/// Checkers pieces reuse the bishop's diagonal rays, which are ordered up-left, down-left, up-right and then down-right.
+pub const DIAGONALS: [usize; 4] = [0, 1, 2, 3];
+const UP_DIAGONALS: [usize; 2] = [0, 2];
+const DOWN_DIAGONALS: [usize; 2] = [1, 3];
+
+/// The diagonals pointing towards the enemy's side of the board. The first team starts at the bottom.
+pub fn get_forward_diagonals(team: u32) -> &'static [usize] {
+    match team {
+        0 => &UP_DIAGONALS,
+        _ => &DOWN_DIAGONALS,
+    }
+}
+
+pub fn generate_diagonal_lookups(board: &Board, from: BitBoard) -> Vec<BitBoard> {
+    BishopPiece.generate_lookup_moves(board, from)
+}
+
+/// The squares of a diagonal ray, ordered from closest to furthest away.
+struct RaySquares {
+    ray: BitBoard,
+    up: bool,
+}
+
+impl RaySquares {
+    fn new(board: &Board, ray: BitBoard, dir: usize) -> RaySquares {
+        let ray = ray & !(BitBoard::max() << board.state.squares);
+        RaySquares {
+            ray,
+            up: UP_DIAGONALS.contains(&dir),
+        }
+    }
+}
+
+impl Iterator for RaySquares {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.ray.is_empty() {
+            return None;
+        }
+
+        let square = if self.up {
+            self.ray.bitscan_reverse()
+        } else {
+            self.ray.bitscan_forward()
+        };
+        self.ray &= !BitBoard::from_lsb(square);
+        Some(square)
+    }
+}
+
+/// How a checkers piece moves and captures.
+pub struct CheckersMovement {
+    /// The diagonals this piece can move along without capturing.
+    pub directions: &'static [usize],
+    /// The diagonals this piece can capture along.
+    pub capture_directions: &'static [usize],
+    /// Flying pieces (like kings in international draughts) move any distance along a diagonal, and can capture pieces from a distance, landing on any empty square behind them.
+    pub flying: bool,
+}
+
+/// The most squares a capture sequence can land on, which is far more than any board has enemy pieces to capture.
+pub const MAX_JUMPS: usize = 64;
+
+/// The squares a capture sequence lands on, in order (ending with its `to` square.)
+pub type CapturePath = ArrayVec<u32, MAX_JUMPS>;
+
+/// Captures keep the squares they capture in their `info`, so that `make_move` can remove them without searching for the sequence again.
+/// Checkers pieces only stand on dark squares, and every dark square has its own `square / 2` (its number in PDN, minus one), so these fit into a bitboard of just the dark squares.
+/// Quiet moves leave their `info` at `0`.
+fn get_capture_info(captured: BitBoard, squares: u32) -> ActionInfo {
+    captured
+        .iter_one_bits(squares)
+        .fold(0, |info, square| info | (1 << (square / 2)))
+}
+
+/// The squares a checkers action captures (see `get_capture_info`.)
+pub fn get_captured(board: &Board, action: &Action) -> BitBoard {
+    let cols = board.state.cols;
+    let mut captured = BitBoard::new();
+    let mut info = action.info;
+    while info != 0 {
+        let square = 2 * info.trailing_zeros();
+        info &= info - 1;
+
+        // Only one of the two squares sharing a number is dark.
+        let (row, col) = (square / cols, square % cols);
+        captured |= BitBoard::from_lsb(square + ((row + col + 1) % 2));
+    }
+    captured
+}
+
+/// How many pieces an action captures.
+pub fn get_capture_count(action: &Action) -> u32 {
+    action.info.count_ones()
+}
+
+struct CaptureSearch<'a, 'b> {
+    board: &'a Board<'b>,
+    lookup: &'a AttackLookup,
+    movement: &'a CheckersMovement,
+    /// Pieces stay on the board until the whole sequence is over, so only the moving piece's own square is empty.
+    empty: BitBoard,
+    /// The pieces that can be captured, which are every enemy piece unless a known sequence is being retraced.
+    enemies: BitBoard,
+}
+
+impl<'a, 'b> CaptureSearch<'a, 'b> {
+    fn new(
+        board: &'a Board<'b>,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        movement: &'a CheckersMovement,
+    ) -> CaptureSearch<'a, 'b> {
+        CaptureSearch {
+            board,
+            lookup: board
+                .attack_lookup
+                .get(piece_type)
+                .expect("Could not find the checkers piece lookup."),
+            movement,
+            empty: !board.state.all_pieces | BitBoard::from_lsb(from),
+            enemies: board.state.all_pieces & !board.state.teams[team as usize],
+        }
+    }
+
+    /// Calls `found` with the captured squares and path of every capture sequence that can't be taken any further.
+    fn search(
+        &self,
+        square: u32,
+        captured: BitBoard,
+        path: &mut CapturePath,
+        found: &mut dyn FnMut(BitBoard, &CapturePath),
+    ) {
+        let mut can_continue = false;
+
+        for &dir in self.movement.capture_directions {
+            let mut ray =
+                RaySquares::new(self.board, self.lookup[square as usize][dir], dir).peekable();
+
+            if self.movement.flying {
+                while ray.next_if(|&square| self.empty.has_bit(square)).is_some() {}
+            }
+
+            let victim = match ray.next() {
+                Some(victim) => BitBoard::from_lsb(victim),
+                None => continue,
+            };
+
+            // Pieces can't be jumped twice, so captured pieces block the way just like friendly ones.
+            if (victim & self.enemies & !captured).is_empty() {
+                continue;
+            }
+
+            for landing in ray {
+                if !self.empty.has_bit(landing) {
+                    break;
+                }
+
+                can_continue = true;
+                path.push(landing);
+                self.search(landing, captured | victim, path, found);
+                path.pop();
+
+                if !self.movement.flying {
+                    break;
+                }
+            }
+        }
+
+        if !can_continue && captured.is_set() {
+            found(captured, path);
+        }
+    }
+}
+
+/// Finds the path a capture takes to capture its pieces, by retracing its sequence over only the pieces it captures.
+/// Sequences that capture the same pieces to land on the same square are the same action, so this returns the first of them.
+pub fn get_capture_sequence(
+    board: &Board,
+    action: &Action,
+    movement: &CheckersMovement,
+) -> Option<CapturePath> {
+    let captured = get_captured(board, action);
+    let mut search =
+        CaptureSearch::new(board, action.piece_type, action.from, action.team, movement);
+    search.enemies = captured;
+
+    let mut sequence = None;
+    search.search(
+        action.from,
+        BitBoard::new(),
+        &mut CapturePath::new(),
+        &mut |found, path| {
+            if sequence.is_none() && found == captured && path[path.len() - 1] == action.to {
+                sequence = Some(path.clone());
+            }
+        },
+    );
+    sequence
+}
+
+/// The empty squares a checkers piece can move to without capturing.
+fn get_quiet_moves(
+    board: &Board,
+    piece_type: usize,
+    from: u32,
+    movement: &CheckersMovement,
+) -> BitBoard {
+    let lookup = board
+        .attack_lookup
+        .get(piece_type)
+        .expect("Could not find the checkers piece lookup.");
+    let empty = !board.state.all_pieces;
+
+    let mut moves = BitBoard::new();
+    for &dir in movement.directions {
+        for to in RaySquares::new(board, lookup[from as usize][dir], dir) {
+            if !empty.has_bit(to) {
+                break;
+            }
+
+            moves |= BitBoard::from_lsb(to);
+
+            if !movement.flying {
+                break;
+            }
+        }
+    }
+    moves
+}
+
+/// Every square a checkers piece can finish a move on, whether it's a quiet move or the end of a capture sequence.
+pub fn get_checkers_moves(
+    board: &Board,
+    piece_type: usize,
+    from: u32,
+    team: u32,
+    movement: &CheckersMovement,
+) -> BitBoard {
+    let mut moves = get_quiet_moves(board, piece_type, from, movement);
+    CaptureSearch::new(board, piece_type, from, team, movement).search(
+        from,
+        BitBoard::new(),
+        &mut CapturePath::new(),
+        &mut |_, path| moves |= BitBoard::from_lsb(path[path.len() - 1]),
+    );
+    moves
+}
+
+/// Every square a checkers action lands on, starting from its `from` square.
+pub fn get_capture_path(board: &Board, action: &Action, movement: &CheckersMovement) -> Vec<u32> {
+    let mut squares = vec![action.from];
+    match get_capture_sequence(board, action, movement) {
+        Some(path) => squares.extend(path),
+        None => squares.push(action.to),
+    }
+    squares
+}
+
+/// Adds every quiet move and capture sequence for a checkers piece.
+/// Capture sequences must be taken all the way to the end, and sequences that capture the same pieces to land on the same square are only added once.
+pub fn add_checkers_actions(
+    actions: &mut dyn MoveBuffer,
+    board: &Board,
+    piece_type: usize,
+    from: u32,
+    team: u32,
+    movement: &CheckersMovement,
+) {
+    for to in get_quiet_moves(board, piece_type, from, movement).iter_one_bits(board.state.squares)
+    {
+        actions.push(Action {
+            from,
+            to,
+            team,
+            piece_type,
+            info: 0,
+            kind: ActionKind::Move,
+        });
+    }
+
+    // This only allocates once a capture has been found.
+    let mut sequences: Vec<(u32, ActionInfo)> = Vec::new();
+    CaptureSearch::new(board, piece_type, from, team, movement).search(
+        from,
+        BitBoard::new(),
+        &mut CapturePath::new(),
+        &mut |captured, path| {
+            let to = path[path.len() - 1];
+            let info = get_capture_info(captured, board.state.squares);
+            if sequences.contains(&(to, info)) {
+                return;
+            }
+
+            sequences.push((to, info));
+            actions.push(Action {
+                from,
+                to,
+                team,
+                piece_type,
+                info,
+                kind: ActionKind::Move,
+            });
+        },
+    );
+}
+
+/// Moves a checkers piece, removing everything it captured along the way and turning it into `promotion` if given.
+pub fn make_checkers_move(board: &mut Board, action: &Action, promotion: Option<PieceType>) {
+    let piece_type = action.piece_type;
+    let color = action.team as usize;
+    let enemy_color = board.get_next_team(action.team) as usize;
+    let from = BitBoard::from_lsb(action.from);
+    let to = BitBoard::from_lsb(action.to);
+    let captured = get_captured(board, action);
+
+    let mut updates = vec![
+        HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+        HistoryUpdate::Team(IndexedPreviousBoard(
+            enemy_color,
+            board.state.teams[enemy_color],
+        )),
+    ];
+    for (piece_type, pieces) in board.state.pieces.iter().enumerate() {
+        updates.push(HistoryUpdate::Piece(IndexedPreviousBoard(
+            piece_type, *pieces,
+        )));
+    }
+
+    board.history.push(HistoryMove {
+        action: *action,
+        state: HistoryState::Any {
+            all_pieces: PreviousBoard(board.state.all_pieces),
+            first_move: PreviousBoard(board.state.first_move),
+            updates,
+        },
+    });
+
+    board.state.teams[color] = (board.state.teams[color] & !from) | to;
+    board.state.teams[enemy_color] &= !captured;
+
+    for pieces in board.state.pieces.iter_mut() {
+        *pieces &= !captured;
+    }
+    board.state.pieces[piece_type] &= !from;
+    board.state.pieces[promotion.unwrap_or(piece_type)] |= to;
+
+    board.state.all_pieces = (board.state.all_pieces & !from & !captured) | to;
+}
@@ -1,25 +1,29 @@
 use crate::{
     bitset::Direction,
     board::{
-        actions::{Action, HistoryMove, HistoryState, IndexedPreviousBoard, PreviousBoard},
+        actions::{
+            Action, ActionKind, HistoryMove, HistoryState, IndexedPreviousBoard, PreviousBoard,
+        },
         edges::Edges,
         fen::{
             FenArgument, FenDecodeError, FenFullMoves, FenOptions, FenState, FenSubMoves,
             FenTeamArgument, PostProcess,
         },
-        game::{Game, MoveRestrictions},
+        game::{Game, GameResults, MoveRestrictions, Resolution},
+        moves::GenerationMode,
         pieces::{Piece, PieceSymbol},
         AttackDirections, BitBoard, Board, Cols, PieceType,
     },
 };
 
-use super::pieces::{
-    down, up, BishopPiece, KingPiece, KnightPiece, PawnPiece, QueenPiece, RookPiece,
+use super::{
+    pieces::{
+        down, is_en_passant_target, up, BishopPiece, KingPiece, KnightPiece, PawnPiece,
+        PromotionRules, QueenPiece, RookPiece,
+    },
+    ChessLegalMoves,
 };
 
-pub const NORMAL_MODE: u32 = 0;
-pub const ATTACKS_MODE: u32 = 1;
-
 pub struct ChessCastlingRights;
 impl FenArgument for ChessCastlingRights {
     fn decode(&self, board: &mut Board, arg: &str) -> Result<(), FenDecodeError> {
@@ -158,6 +162,7 @@ impl FenArgument for ChessEnPassant {
                 team: previous_team,
                 piece_type: 0,
                 info: 0,
+                kind: ActionKind::Move,
             },
             state: HistoryState::None,
         });
@@ -173,11 +178,7 @@ impl FenArgument for ChessEnPassant {
 
         let last_move =
             last_move.expect("The last move for exporting an en passant FEN must be Some.");
-        if last_move.action.piece_type != 0 {
-            return "-".to_string();
-        }
-
-        if (last_move.action.from.abs_diff(last_move.action.to) != (2 * board.state.cols)) {
+        if !is_en_passant_target(board, &last_move.action) {
             return "-".to_string();
         }
 
@@ -211,6 +212,13 @@ impl PostProcess for ChessPostProcess {
     }
 }
 
+/// Whether or not the given team's king is attacked. Teams without a king (like white in horde) can't be in check.
+pub fn is_king_attacked(board: &Board, team: u32) -> bool {
+    let king_board = board.state.teams[team as usize] & board.state.pieces[5];
+    let enemy_team = board.get_next_team(team);
+    king_board.is_set() && (board.get_attacks(enemy_team) & king_board).is_set()
+}
+
 pub struct ChessMoveRestrictions;
 impl MoveRestrictions for ChessMoveRestrictions {
     fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
@@ -222,25 +230,78 @@ impl MoveRestrictions for ChessMoveRestrictions {
 
         let current_team = board.state.moving_team;
 
+        // Teams without a king (like white in horde) can't be put in check.
+        if (kings & board.state.teams[current_team as usize]).is_empty() {
+            return true;
+        }
+
         board.make_move(action);
         let kings = board.state.pieces[5];
         let king_board = board.state.teams[current_team as usize] & kings;
-        let in_check = board.can_move(board.state.moving_team, king_board, ATTACKS_MODE);
+        let in_check = board.can_move(board.state.moving_team, king_board, GenerationMode::Attacks);
         board.undo_move();
         !in_check
     }
 
+    fn is_in_check(&self, board: &mut Board) -> bool {
+        is_king_attacked(board, board.state.moving_team)
+    }
+
+    fn gives_check(&self, board: &mut Board, action: &Action) -> bool {
+        let enemy_team = board.get_next_team(board.state.moving_team);
+
+        board.make_move(action);
+        let gives_check = is_king_attacked(board, enemy_team);
+        board.undo_move().unwrap();
+        gives_check
+    }
+
     fn duplicate(&self) -> Box<dyn MoveRestrictions> {
         Box::new(ChessMoveRestrictions)
     }
 }
 
-const PAWN: &dyn Piece = &PawnPiece;
-const KNIGHT: &dyn Piece = &KnightPiece;
-const BISHOP: &dyn Piece = &BishopPiece;
-const ROOK: &dyn Piece = &RookPiece;
-const QUEEN: &dyn Piece = &QueenPiece;
-const KING: &dyn Piece = &KingPiece;
+pub struct ChessResolution;
+impl Resolution for ChessResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        if !legal_moves.is_empty() {
+            return GameResults::Ongoing;
+        }
+
+        let current_team = board.state.moving_team;
+        let enemy_team = board.get_next_team(current_team);
+        let king_board = board.state.teams[current_team as usize] & board.state.pieces[5];
+        let in_check = (board.get_attacks(enemy_team) & king_board).is_set();
+
+        if in_check {
+            GameResults::Win(enemy_team)
+        } else {
+            GameResults::Draw
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(ChessResolution)
+    }
+}
+
+pub(crate) const PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 1,
+        mandatory: true,
+        pieces: &[1, 2, 3, 4],
+        limits: None,
+    },
+    double_move_ranks: &[],
+};
+pub(crate) const KNIGHT: &dyn Piece = &KnightPiece;
+pub(crate) const BISHOP: &dyn Piece = &BishopPiece;
+pub(crate) const ROOK: &dyn Piece = &RookPiece;
+pub(crate) const QUEEN: &dyn Piece = &QueenPiece;
+pub(crate) const KING: &dyn Piece = &KingPiece;
+
+/// The values of `PAWN`, `KNIGHT`, `BISHOP`, `ROOK`, `QUEEN` and `KING`, in centipawns.
+pub(crate) const CHESS_PIECE_VALUES: [i32; 6] = [100, 300, 300, 500, 900, 20000];
 
 pub struct Chess;
 
@@ -250,7 +311,10 @@ impl Chess {
             teams: 2,
             turns: 1,
             pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
-            move_restrictions: Box::new(ChessMoveRestrictions),
+            piece_values: CHESS_PIECE_VALUES.to_vec(),
+            move_restrictions: Box::new(ChessLegalMoves),
+            resolution: Box::new(ChessResolution),
+            custom_state: None,
             fen_options: FenOptions {
                 state: FenState { first_moves: false },
                 args: vec![
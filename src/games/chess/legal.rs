@@ -0,0 +1,287 @@
+use arrayvec::ArrayVec;
+
+use crate::board::{
+    actions::{Action, ActionKind},
+    game::MoveRestrictions,
+    moves::{GenerationMode, MoveBuffer},
+    BitBoard, Board,
+};
+
+use super::{
+    pieces::{get_blocked_ray, get_castling_squares, CastlingSquares},
+    ChessMoveRestrictions,
+};
+
+const PAWN_PIECE_TYPE: usize = 0;
+const BISHOP_PIECE_TYPE: usize = 2;
+const ROOK_PIECE_TYPE: usize = 3;
+const QUEEN_PIECE_TYPE: usize = 4;
+const KING_PIECE_TYPE: usize = 5;
+
+/// Castling for kings, and en passant for pawns.
+const SPECIAL_MOVE: usize = 1;
+
+/// The queen's first four rays are orthogonal (like the rook's), and the last four are diagonal (like the bishop's.)
+const ORTHOGONAL_RAYS: u32 = 4;
+const QUEEN_RAYS: u32 = 8;
+
+/// Everything needed to check the legality of a move without making it, computed once per position.
+pub struct ChessLegality {
+    /// The squares a non-king move has to land on: anywhere when not in check, only the checker (or squares between it and the king) when in check, and nowhere in double check.
+    pub check_mask: BitBoard,
+    /// Each piece that's pinned to its king, with the ray (from the king up to and including the pinner) that it can still move along. There's at most one pin per ray.
+    pub pins: ArrayVec<(BitBoard, BitBoard), 8>,
+    /// Every square the enemy attacks, with the king taken off the board so that it can't step back along a checking ray.
+    pub king_danger: BitBoard,
+}
+
+impl ChessLegality {
+    pub fn new(board: &mut Board, team: u32) -> ChessLegality {
+        let king = board.state.pieces[KING_PIECE_TYPE] & board.state.teams[team as usize];
+        let king_bit = king.bitscan_forward() as usize;
+        let enemy_team = board.get_next_team(team);
+        let enemies = board.state.teams[enemy_team as usize];
+        let queen_lookup = &board.attack_lookup[QUEEN_PIECE_TYPE];
+
+        // Pieces attack the same way in both directions (besides pawns, which attack the squares that enemy pawns would attack the king from), so moving each piece from the king finds its checkers.
+        let mut checkers = BitBoard::new();
+        for (piece_type, piece) in board.game.pieces.iter().enumerate() {
+            let attacks = piece.get_moves(board, king, piece_type, team, GenerationMode::Attacks);
+            checkers |= attacks & board.state.pieces[piece_type] & enemies;
+        }
+
+        let check_mask = match checkers.count_ones() {
+            0 => BitBoard::max(),
+            1 => (0..QUEEN_RAYS)
+                .map(|dir| {
+                    get_blocked_ray(king, king_bit, dir, queen_lookup, board.state.all_pieces)
+                })
+                .find(|ray| (*ray & checkers).is_set())
+                .unwrap_or(checkers),
+            _ => BitBoard::new(),
+        };
+
+        let queens = board.state.pieces[QUEEN_PIECE_TYPE];
+        let mut pins = ArrayVec::new();
+        for dir in 0..QUEEN_RAYS {
+            let pinners = if dir < ORTHOGONAL_RAYS {
+                board.state.pieces[ROOK_PIECE_TYPE] | queens
+            } else {
+                board.state.pieces[BISHOP_PIECE_TYPE] | queens
+            };
+
+            let ray = get_blocked_ray(king, king_bit, dir, queen_lookup, board.state.all_pieces);
+            let pinned = ray & board.state.teams[team as usize];
+            if pinned.is_empty() {
+                continue;
+            }
+
+            let blockers = board.state.all_pieces & !pinned;
+            let ray = get_blocked_ray(king, king_bit, dir, queen_lookup, blockers);
+            if (ray & blockers & enemies & pinners).is_set() {
+                pins.push((pinned, ray));
+            }
+        }
+
+        board.state.all_pieces ^= king;
+        let king_danger = board.get_move_mask(enemy_team, GenerationMode::Attacks);
+        board.state.all_pieces ^= king;
+
+        ChessLegality {
+            check_mask,
+            pins,
+            king_danger,
+        }
+    }
+
+    /// En passant (which can uncover checks in ways pins don't account for) is left to `ChessMoveRestrictions`, as are drops.
+    pub fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        let from = BitBoard::from_lsb(action.from);
+        let to = BitBoard::from_lsb(action.to);
+        if (to & board.state.pieces[KING_PIECE_TYPE]).is_set() {
+            return false;
+        }
+
+        let is_en_passant = action.info == SPECIAL_MOVE && action.piece_type == PAWN_PIECE_TYPE;
+        if action.kind != ActionKind::Move || is_en_passant {
+            return ChessMoveRestrictions.is_legal(board, action);
+        }
+
+        if action.piece_type == KING_PIECE_TYPE {
+            if action.info == SPECIAL_MOVE {
+                return self.is_castling_legal(board, action);
+            }
+
+            return (to & self.king_danger).is_empty();
+        }
+
+        if (to & self.check_mask).is_empty() {
+            return false;
+        }
+
+        match self.pins.iter().find(|(pinned, _)| *pinned == from) {
+            Some((_, ray)) => (to & *ray).is_set(),
+            None => true,
+        }
+    }
+
+    /// Castling moves the king from `action.from` towards the rook on `action.to`, which it can't do out of check or through (or into) an attacked square.
+    fn is_castling_legal(&self, board: &Board, action: &Action) -> bool {
+        let from = BitBoard::from_lsb(action.from);
+        if (from & self.check_mask).is_empty() {
+            return false;
+        }
+
+        let CastlingSquares {
+            left_king,
+            left_rook,
+            right_king,
+            right_rook,
+        } = get_castling_squares(board, action.team);
+        let (king_to, rook_to) = if action.to < action.from {
+            (left_king, left_rook)
+        } else {
+            (right_king, right_rook)
+        };
+
+        let king_to_bit = king_to.bitscan_forward();
+        let crossed = if king_to_bit < action.from {
+            BitBoard::starting_at_lsb(king_to_bit, action.from - king_to_bit + 1)
+        } else {
+            BitBoard::starting_at_lsb(action.from, king_to_bit - action.from + 1)
+        };
+        if (crossed & self.king_danger).is_set() {
+            return false;
+        }
+
+        // The castling rook can be what shields the king's destination along the back rank (in Chess960), so the king's rays are checked again once both have moved.
+        let enemies = board.state.teams[board.get_next_team(action.team) as usize];
+        let sliders =
+            (board.state.pieces[ROOK_PIECE_TYPE] | board.state.pieces[QUEEN_PIECE_TYPE]) & enemies;
+        let occupied =
+            (board.state.all_pieces & !(from | BitBoard::from_lsb(action.to))) | king_to | rook_to;
+        let queen_lookup = &board.attack_lookup[QUEEN_PIECE_TYPE];
+        (0..ORTHOGONAL_RAYS).all(|dir| {
+            let ray = get_blocked_ray(king_to, king_to_bit as usize, dir, queen_lookup, occupied);
+            (ray & sliders).is_empty()
+        })
+    }
+}
+
+/// Chess finds its legal moves directly from checks and pins (see `ChessLegality`), instead of making and undoing every pseudolegal move.
+/// This relies on the standard chess pieces (in their usual order), so variants with other pieces use `ChessMoveRestrictions` instead.
+pub struct ChessLegalMoves;
+impl MoveRestrictions for ChessLegalMoves {
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        ChessMoveRestrictions.is_legal(board, action)
+    }
+
+    fn retain_legal_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        let team = board.state.moving_team;
+        let king = board.state.pieces[KING_PIECE_TYPE] & board.state.teams[team as usize];
+        if king.count_ones() != 1 {
+            moves.retain(&mut |action| self.is_legal(board, action));
+            return;
+        }
+
+        let legality = ChessLegality::new(board, team);
+        moves.retain(&mut |action| legality.is_legal(board, action));
+    }
+
+    fn is_in_check(&self, board: &mut Board) -> bool {
+        ChessMoveRestrictions.is_in_check(board)
+    }
+
+    fn gives_check(&self, board: &mut Board, action: &Action) -> bool {
+        ChessMoveRestrictions.gives_check(board, action)
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(ChessLegalMoves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::MoveRestrictions, moves::GenerationMode, Board},
+        games::chess::{Chess, ChessMoveRestrictions},
+    };
+
+    fn assert_same_moves(board: &mut Board, depth: u32) {
+        let mut legal_moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        let mut checked_moves = board.generate_moves(GenerationMode::Normal);
+        checked_moves.retain(|action| ChessMoveRestrictions.is_legal(board, action));
+        let mut checked_moves = checked_moves
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        legal_moves.sort();
+        checked_moves.sort();
+        assert_eq!(legal_moves, checked_moves, "{}", board.to_fen());
+
+        if depth == 0 {
+            return;
+        }
+        for action in board.generate_legal_moves(GenerationMode::Normal) {
+            board.make_move(&action);
+            assert_same_moves(board, depth - 1);
+            board.undo_move().unwrap();
+        }
+    }
+
+    #[test]
+    fn pins_and_checks() {
+        let chess = Chess::create();
+        let mut board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        assert_same_moves(&mut board, 2);
+
+        // The en passant capture on c6 would leave white's king in check from the rook on h5.
+        board = Board::new(&chess, (8, 8), "8/8/8/KPp4r/8/8/8/6k1 w - c6 0 2");
+        assert_same_moves(&mut board, 2);
+
+        // A double check from the knight and the bishop, where only the king can move.
+        board = Board::new(&chess, (8, 8), "4k3/8/8/b7/8/3n4/8/4K2R w K - 0 1");
+        assert!(board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .all(|action| action.piece_type == 5));
+        assert_same_moves(&mut board, 2);
+
+        // Castling would uncover the rook on a1, which the castling rook on b1 was shielding the king from.
+        board = Board::new(&chess, (8, 8), "k7/8/8/8/8/8/8/rRK5 w Q - 0 1");
+        assert!(!board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .any(|action| action.piece_type == 5 && action.info == 1));
+        assert_same_moves(&mut board, 2);
+
+        // The queen on h8 checks the king on b8, which can't castle to c8 even though the rook would block the check once it lands on d8.
+        board = Board::new(&chess, (8, 8), "rk5Q/8/8/8/8/8/8/K7 b q - 0 1");
+        assert!(!board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .any(|action| action.piece_type == 5 && action.info == 1));
+        assert_same_moves(&mut board, 2);
+
+        // The queen on f2 checks the king and attacks f1, so white can't castle either way.
+        board = Board::new(&chess, (8, 8), "r3k2r/8/8/8/8/8/5q2/R3K2R w KQ - 0 1");
+        assert_same_moves(&mut board, 2);
+
+        // Both sides can castle either way, until a rook or king move (or an attack) takes it away.
+        board = Board::new(&chess, (8, 8), "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        assert_same_moves(&mut board, 2);
+
+        // The bishop on d2 is pinned against the king by the queen on a5, and can only move along the pin.
+        board = Board::new(&chess, (8, 8), "4k3/8/8/q7/8/8/3B4/4K3 w - - 0 1");
+        assert_same_moves(&mut board, 3);
+    }
+}
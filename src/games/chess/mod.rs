@@ -1,14 +1,23 @@
 mod game;
+mod legal;
 mod suite;
 pub mod pieces;
+mod variants;
 
 pub use game::*;
+pub use legal::*;
+pub use variants::*;
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        board::Board,
-        board::{actions::HistoryMove, game::Game},
+        board::{BitBoard, Board},
+        board::{
+            actions::{Action, HistoryMove},
+            fen::{FenArgument, FenHands},
+            game::Game,
+            moves::GenerationMode,
+        },
         games::chess::Chess,
     };
 
@@ -64,6 +73,42 @@ mod tests {
         board.assert_perfts([48, 2039, 97862, 4085603]);
     }
 
+    #[test]
+    fn attackers() {
+        let chess = Chess::create();
+        let mut board = Board::new(
+            &chess,
+            (8, 8),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        );
+        let squares = |board: &Board, squares: &[&str]| {
+            squares.iter().fold(BitBoard::new(), |bitboard, square| {
+                bitboard | BitBoard::from_lsb(board.decode_position(square.to_string()).unwrap())
+            })
+        };
+
+        // Black's attacks are found even though it's white's move, like d8 (which only black attacks.)
+        let d5 = board.decode_position("d5".to_string()).unwrap();
+        let d8 = board.decode_position("d8".to_string()).unwrap();
+        for _ in 0..2 {
+            assert_eq!(board.attackers_to(d5, 0), squares(&board, &["c3", "e4"]));
+            assert_eq!(
+                board.attackers_to(d5, 1),
+                squares(&board, &["b6", "e6", "f6"])
+            );
+            assert_eq!(
+                board.attackers_to(d8, 1),
+                squares(&board, &["a8", "e7", "e8"])
+            );
+            assert!(board.is_attacked(d8, 1));
+            assert!(!board.is_attacked(d8, 0));
+            assert!(board.can_move(1, BitBoard::from_lsb(d8), GenerationMode::Attacks));
+            assert!(!board.can_move(0, BitBoard::from_lsb(d8), GenerationMode::Attacks));
+
+            board.enable_attack_maps();
+        }
+    }
+
     #[test]
     fn position_three() {
         let chess = Chess::create();
@@ -115,4 +160,50 @@ mod tests {
 
         board.assert_perfts([24, 496, 9483, 182838, 3605103]);
     }
+
+    #[test]
+    fn drops() {
+        let chess = Chess::create();
+        let mut board = Board::new(
+            &chess,
+            (8, 8),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        );
+
+        // A queen in hand can be dropped onto any of the 32 empty squares.
+        FenHands.decode(&mut board, "Q").unwrap();
+        assert_eq!(FenHands.encode(&board), "Q");
+        board.assert_perfts([52, 1020]);
+
+        let action = board.decode_action("Q@d6", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(
+            board.to_fen(),
+            "rnbqkbnr/pppppppp/3Q4/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 1 2"
+        );
+        assert_eq!(FenHands.encode(&board), "-");
+
+        board.undo_move().unwrap();
+        assert_eq!(FenHands.encode(&board), "Q");
+    }
+
+    #[test]
+    fn null_move() {
+        let chess = Chess::create();
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let mut board = Board::new(&chess, (8, 8), fen);
+
+        let pass = Action::pass(board.state.moving_team);
+        assert_eq!(board.encode_action(&pass), "pass");
+
+        board.make_move(&pass);
+        assert_eq!(board.state.moving_team, 1);
+        board.assert_perfts([20, 400]);
+
+        board.undo_move().unwrap();
+        assert_eq!(board.to_fen(), fen);
+        assert!(board
+            .decode_action("0000", GenerationMode::Normal)
+            .is_none());
+    }
 }
@@ -0,0 +1,57 @@
+use crate::board::{
+    moves::GenerationMode,
+    pieces::{Piece, PieceKind, PieceSymbol},
+    AttackDirections, BitBoard, Board,
+};
+
+/// The alfil (shatranj's bishop) leaps exactly two squares diagonally, jumping over anything in between.
+pub struct AlfilPiece;
+
+impl Piece for AlfilPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('b')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let cols = board.state.cols;
+        let deep_edges = &board.state.edges[1];
+
+        let moves = (from & !deep_edges.top & !deep_edges.left)
+            .up(2, cols)
+            .left(2)
+            | (from & !deep_edges.top & !deep_edges.right)
+                .up(2, cols)
+                .right(2)
+            | (from & !deep_edges.bottom & !deep_edges.left)
+                .down(2, cols)
+                .left(2)
+            | (from & !deep_edges.bottom & !deep_edges.right)
+                .down(2, cols)
+                .right(2);
+
+        vec![moves]
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Leaper
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the alfil attack lookup.");
+        lookup[from.bitscan_forward() as usize][0]
+    }
+}
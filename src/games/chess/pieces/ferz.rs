@@ -0,0 +1,51 @@
+use crate::board::{
+    moves::GenerationMode,
+    pieces::{Piece, PieceKind, PieceSymbol},
+    AttackDirections, BitBoard, Board,
+};
+
+/// The ferz steps one square diagonally. It's the met in makruk, and the counsellor (usually written as a queen) in shatranj.
+pub struct FerzPiece {
+    pub symbol: char,
+}
+
+impl Piece for FerzPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char(self.symbol)
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let cols = board.state.cols;
+        let edges = &board.state.edges[0];
+
+        let moves = (from & !edges.top & !edges.left).up(1, cols).left(1)
+            | (from & !edges.top & !edges.right).up(1, cols).right(1)
+            | (from & !edges.bottom & !edges.left).down(1, cols).left(1)
+            | (from & !edges.bottom & !edges.right).down(1, cols).right(1);
+
+        vec![moves]
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Leaper
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the ferz attack lookup.");
+        lookup[from.bitscan_forward() as usize][0]
+    }
+}
@@ -0,0 +1,54 @@
+use crate::board::{
+    moves::GenerationMode,
+    pieces::{Piece, PieceKind, PieceSymbol},
+    AttackDirections, BitBoard, Board,
+};
+
+use super::up;
+
+/// The khon (makruk's bishop) steps one square diagonally, or one square straight forward.
+pub struct KhonPiece;
+
+impl Piece for KhonPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('s')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let cols = board.state.cols;
+        let edges = &board.state.edges[0];
+
+        let diagonals = (from & !edges.top & !edges.left).up(1, cols).left(1)
+            | (from & !edges.top & !edges.right).up(1, cols).right(1)
+            | (from & !edges.bottom & !edges.left).down(1, cols).left(1)
+            | (from & !edges.bottom & !edges.right).down(1, cols).right(1);
+
+        vec![
+            diagonals | up(&(from & !edges.top), 1, cols, 0),
+            diagonals | up(&(from & !edges.bottom), 1, cols, 1),
+        ]
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Leaper
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the khon attack lookup.");
+        lookup[from.bitscan_forward() as usize][team as usize]
+    }
+}
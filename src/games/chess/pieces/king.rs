@@ -2,13 +2,14 @@ use crate::{
     bitset::Direction,
     board::{
         actions::{
-            Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard,
+            Action, ActionKind, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard,
+            PreviousBoard,
         },
         edges::Edges,
-        pieces::{Piece, PieceSymbol},
+        moves::{GenerationMode, MoveBuffer},
+        pieces::{Piece, PieceKind, PieceSymbol},
         AttackDirections, BitBoard, Board, Cols, PieceType,
     },
-    games::chess::game::ATTACKS_MODE,
 };
 
 const NORMAL_KING_MOVE: usize = 0;
@@ -34,23 +35,42 @@ fn down_one(from: BitBoard, cols: Cols, edges: &Edges) -> BitBoard {
     (from & !edges.bottom).down(1, cols)
 }
 
-impl KingPiece {
-    fn make_castling_move(&self, board: &mut Board, action: &Action, from: BitBoard, to: BitBoard) {
-        let cols = board.state.cols;
-        let mut left_center = BitBoard::from_lsb(if cols % 2 == 0 {
-            (cols / 2) - 1
-        } else {
-            cols / 2
-        });
+pub struct CastlingSquares {
+    pub left_king: BitBoard,
+    pub left_rook: BitBoard,
+    pub right_king: BitBoard,
+    pub right_rook: BitBoard,
+}
 
-        if action.team == 0 {
-            left_center = left_center.down(7, cols);
-        }
+/// Finds where the king and rook land after castling on the given team's back rank.
+///
+/// Just like traditional chess, the king lands on the third column from the side it castles towards, with the rook right beside it towards the center.
+/// That means `c1`/`d1` and `g1`/`f1` on an 8x8 board, but `c1`/`d1` and `i1`/`h1` on a 10x8 board (like in Capablanca chess.)
+pub fn get_castling_squares(board: &Board, team: u32) -> CastlingSquares {
+    let rows = board.state.rows;
+    let cols = board.state.cols;
+    let row = match team {
+        1 => 0,
+        _ => rows - 1,
+    };
+    let row_start = row * cols;
+
+    CastlingSquares {
+        left_king: BitBoard::from_lsb(row_start + 2),
+        left_rook: BitBoard::from_lsb(row_start + 3),
+        right_king: BitBoard::from_lsb(row_start + cols - 2),
+        right_rook: BitBoard::from_lsb(row_start + cols - 3),
+    }
+}
 
-        let castle_left_king = left_center.left(1);
-        let castle_left_rook = left_center;
-        let castle_right_king = left_center.right(3);
-        let castle_right_rook = left_center.right(2);
+impl KingPiece {
+    fn make_castling_move(&self, board: &mut Board, action: &Action, from: BitBoard, to: BitBoard) {
+        let CastlingSquares {
+            left_king: castle_left_king,
+            left_rook: castle_left_rook,
+            right_king: castle_right_king,
+            right_rook: castle_right_rook,
+        } = get_castling_squares(board, action.team);
 
         let dir = if action.from < action.to {
             Direction::RIGHT
@@ -137,6 +157,10 @@ impl Piece for KingPiece {
         true
     }
 
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Leaper
+    }
+
     fn can_move_mask(
         &self,
         board: &Board,
@@ -144,7 +168,7 @@ impl Piece for KingPiece {
         from_bit: u32,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
         to: BitBoard,
     ) -> BitBoard {
         self.get_attack_lookup(board, piece_type).unwrap()[from_bit as usize][0]
@@ -157,7 +181,7 @@ impl Piece for KingPiece {
         from: BitBoard,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) -> BitBoard {
         let lookup = self.get_attack_lookup(board, piece_type);
         match lookup {
@@ -235,15 +259,14 @@ impl Piece for KingPiece {
 
     fn add_actions(
         &self,
-        actions: &mut Vec<Action>,
+        actions: &mut dyn MoveBuffer,
         board: &Board,
         piece_type: usize,
         from: u32,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) {
         let rows = board.state.rows;
-        let cols = board.state.cols;
         let board_len = board.state.squares;
 
         let from_board = BitBoard::from_lsb(from);
@@ -261,6 +284,7 @@ impl Piece for KingPiece {
                 team,
                 info: NORMAL_KING_MOVE,
                 piece_type,
+                kind: ActionKind::Move,
             });
         }
 
@@ -288,27 +312,15 @@ impl Piece for KingPiece {
 
         /*
             FRC Castling brings us to the same positions that traditional chess castling would.
-            To extend for bigger board sizes, we'll have to create two castling spots for left and right.
-            We'll define these positions in terms of `left_center`.
-
-            `left_center` is the centermost point on the bottom row.
-            If there are two center points, `left_center` is the center point on the left.
+            See `get_castling_squares` for how those positions are found on bigger boards.
         */
 
-        let mut left_center = BitBoard::from_lsb(if board.state.cols % 2 == 0 {
-            (cols / 2) - 1
-        } else {
-            cols / 2
-        });
-
-        if team == 0 {
-            left_center = left_center.down(7, cols);
-        }
-
-        let castle_left_king = left_center.left(1);
-        let castle_left_rook = left_center;
-        let castle_right_king = left_center.right(3);
-        let castle_right_rook = left_center.right(2);
+        let CastlingSquares {
+            left_king: castle_left_king,
+            left_rook: castle_left_rook,
+            right_king: castle_right_king,
+            right_rook: castle_right_rook,
+        } = get_castling_squares(board, team);
 
         let castle_left = castle_left_king | castle_left_rook;
         let castle_right = castle_right_king | castle_right_rook;
@@ -353,12 +365,14 @@ impl Piece for KingPiece {
             }
             .bitscan_forward();
 
-            let between_king_dest = match dir {
-                Direction::LEFT => BitBoard::starting_at_lsb(king_dest, from - king_dest + 1),
-                Direction::RIGHT => BitBoard::starting_at_lsb(from, king_dest - from + 1),
+            // In Chess960, the king can end up on the other side of where it started (eg. castling from b1 to c1), so this doesn't depend on `dir`.
+            let between_king_dest = if king_dest < from {
+                BitBoard::starting_at_lsb(king_dest, from - king_dest + 1)
+            } else {
+                BitBoard::starting_at_lsb(from, king_dest - from + 1)
             };
 
-            let attack_mask = board.get_move_mask(board.get_next_team(team), ATTACKS_MODE);
+            let attack_mask = board.get_attacks(board.get_next_team(team));
 
             if (between_king_dest & attack_mask).is_set() {
                 continue;
@@ -370,6 +384,7 @@ impl Piece for KingPiece {
                 team,
                 info: CASTLING_MOVE,
                 piece_type,
+                kind: ActionKind::Move,
             });
         }
     }
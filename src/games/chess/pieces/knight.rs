@@ -3,10 +3,10 @@ use crate::{
     board::{
         actions::{Action, HistoryMove, HistoryState, IndexedPreviousBoard, PreviousBoard},
         edges::Edges,
-        pieces::{Piece, PieceSymbol},
+        moves::GenerationMode,
+        pieces::{Piece, PieceKind, PieceSymbol},
         AttackDirections, BitBoard, Board, Cols, PieceType,
     },
-    games::chess::game::ATTACKS_MODE,
 };
 
 pub struct KnightPiece;
@@ -73,6 +73,10 @@ impl Piece for KnightPiece {
         true
     }
 
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Leaper
+    }
+
     fn can_move_mask(
         &self,
         board: &Board,
@@ -80,7 +84,7 @@ impl Piece for KnightPiece {
         from_bit: u32,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
         to: BitBoard,
     ) -> BitBoard {
         self.get_attack_lookup(board, piece_type).unwrap()[from_bit as usize][0]
@@ -93,7 +97,7 @@ impl Piece for KnightPiece {
         from: BitBoard,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) -> BitBoard {
         let lookup = self.get_attack_lookup(board, piece_type);
         match lookup {
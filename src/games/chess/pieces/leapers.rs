@@ -0,0 +1,44 @@
+use crate::board::{AttackDirections, BitBoard, Board};
+
+/// A leap that's blocked whenever a piece stands on its `leg`, like the xiangqi horse and elephant.
+/// Both offsets are `(rows, cols)` away from the leaping piece, where negative rows point up the board.
+pub struct LameLeap {
+    pub leg: (i32, i32),
+    pub target: (i32, i32),
+}
+
+/// Generates the lookup for a lame leaper, stored as alternating leg and target boards for every leap that fits on the board.
+pub fn generate_lame_leaps(board: &Board, from: BitBoard, leaps: &[LameLeap]) -> AttackDirections {
+    let rows = board.state.rows as i32;
+    let cols = board.state.cols as i32;
+    let from_bit = from.bitscan_forward() as i32;
+    let (row, col) = (from_bit / cols, from_bit % cols);
+
+    let square = |(row_offset, col_offset): (i32, i32)| {
+        let (row, col) = (row + row_offset, col + col_offset);
+        if row < 0 || col < 0 || row >= rows || col >= cols {
+            None
+        } else {
+            Some(BitBoard::from_lsb((row * cols + col) as u32))
+        }
+    };
+
+    let mut lookups = Vec::with_capacity(leaps.len() * 2);
+    for leap in leaps {
+        if let (Some(leg), Some(target)) = (square(leap.leg), square(leap.target)) {
+            lookups.push(leg);
+            lookups.push(target);
+        }
+    }
+    lookups
+}
+
+pub fn get_lame_leaper_moves(board: &Board, leaps: &AttackDirections) -> BitBoard {
+    let mut moves = BitBoard::new();
+    for leap in leaps.chunks_exact(2) {
+        if (leap[0] & board.state.all_pieces).is_empty() {
+            moves |= leap[1];
+        }
+    }
+    moves
+}
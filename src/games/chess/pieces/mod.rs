@@ -1,9 +1,17 @@
+mod alfil;
+mod ferz;
+mod khon;
 mod king;
 mod knight;
+mod leapers;
 mod pawn;
 mod sliders;
 
+pub use alfil::*;
+pub use ferz::*;
+pub use khon::*;
 pub use king::*;
 pub use knight::*;
+pub use leapers::*;
 pub use pawn::*;
 pub use sliders::*;
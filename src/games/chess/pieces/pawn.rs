@@ -2,13 +2,14 @@ use crate::{
     bitset::Direction,
     board::{
         actions::{
-            Action, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard, PreviousBoard,
+            Action, ActionKind, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard,
+            PreviousBoard,
         },
         edges::Edges,
+        moves::{GenerationMode, MoveBuffer, MoveList, MoveStage},
         pieces::{Piece, PieceSymbol},
         AttackDirections, BitBoard, Board, Cols, PieceType,
     },
-    games::chess::game::ATTACKS_MODE,
 };
 
 const NORMAL_PAWN_MOVE: usize = 0;
@@ -17,7 +18,47 @@ fn promotion_move(piece_type: PieceType) -> usize {
     piece_type + 2
 }
 
-pub struct PawnPiece;
+pub struct PromotionRules {
+    /// How many ranks (counting back from the far side of the board) pawns are able to promote on.
+    /// Chess only promotes on the last rank, while Grand Chess promotes on the last three ranks.
+    pub zone: u32,
+    /// Whether pawns must promote as soon as they enter the promotion zone.
+    /// If not, pawns can choose to stay as pawns until they reach the last rank.
+    pub mandatory: bool,
+    /// The piece types that a pawn is allowed to promote to, in the order that their promotion moves are generated.
+    pub pieces: &'static [PieceType],
+    /// Limits promotion based on material, indexed by piece type. A pawn can only promote to a piece type if its team has fewer than `limits[piece_type]` of them.
+    /// Grand Chess uses this to only allow promoting to pieces that have already been captured.
+    pub limits: Option<&'static [u32]>,
+}
+
+impl PromotionRules {
+    pub fn get_zone(&self, board: &Board, team: u32) -> BitBoard {
+        let rows = board.state.rows;
+        let mut zone = BitBoard::new();
+        for rank in (rows - self.zone + 1)..=rows {
+            zone |= get_rank(board, rank, team);
+        }
+        zone
+    }
+
+    pub fn can_promote_to(&self, board: &Board, piece_type: PieceType, team: u32) -> bool {
+        match self.limits {
+            Some(limits) => {
+                let pieces = board.state.get_piece_team_board(piece_type, team as usize);
+                pieces.count_ones() < limits[piece_type]
+            }
+            None => true,
+        }
+    }
+}
+
+pub struct PawnPiece {
+    pub promotion: PromotionRules,
+    /// Pawns standing on any of these ranks are always able to double move, even if they've already moved (eg. the first and second ranks in horde.)
+    /// Otherwise, pawns can only double move on their first move.
+    pub double_move_ranks: &'static [u32],
+}
 
 pub fn up(bitboard: &BitBoard, shift: u32, cols: Cols, team: u32) -> BitBoard {
     match team {
@@ -35,7 +76,128 @@ pub fn down(bitboard: &BitBoard, shift: u32, cols: Cols, team: u32) -> BitBoard
     }
 }
 
+/// Gets the given rank, counting from `team`'s side of the board (so the first rank is the bottom row for the first team, and the top row for the second.)
+pub fn get_rank(board: &Board, rank: u32, team: u32) -> BitBoard {
+    let row = match team {
+        1 => rank - 1,
+        _ => board.state.rows - rank,
+    };
+    BitBoard::starting_at_lsb(row * board.state.cols, board.state.cols)
+}
+
+/// Whether or not the given move is a pawn double move that can be captured en passant.
+/// Pawns that double move from their own back rank (which can happen in horde) can't be captured en passant.
+pub fn is_en_passant_target(board: &Board, action: &Action) -> bool {
+    action.piece_type == 0
+        && action.to.abs_diff(action.from) == (2 * board.state.cols)
+        && (BitBoard::from_lsb(action.from) & get_rank(board, 1, action.team)).is_empty()
+}
+
 impl PawnPiece {
+    /// The squares this pawn can capture on, including the en passant square.
+    fn get_captures(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+    ) -> BitBoard {
+        let mut capture_requirements = board.state.all_pieces;
+        if let Some(last_move) = board.history.last() {
+            if is_en_passant_target(board, &last_move.action) {
+                capture_requirements |= up(
+                    &BitBoard::from_lsb(last_move.action.from),
+                    1,
+                    board.state.cols,
+                    board.get_next_team(team),
+                );
+            }
+        }
+
+        self.get_attack_lookup(board, piece_type).unwrap()[from.bitscan_forward() as usize]
+            [team as usize]
+            & capture_requirements
+    }
+
+    /// Adds a move to each of `bit_actions`, with every promotion it can make in the promotion zone, and marking en passant captures.
+    fn add_move_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        bit_actions: BitBoard,
+    ) {
+        if bit_actions.is_empty() {
+            return;
+        }
+
+        let rows = board.state.rows;
+        let promotion_zone = self.promotion.get_zone(board, team);
+        let last_rank = get_rank(board, rows, team);
+
+        let cols = board.state.cols;
+
+        for bit in bit_actions.iter_one_bits(board.state.squares) {
+            let to = BitBoard::from_lsb(bit);
+            if (to & promotion_zone).is_set() {
+                if !self.promotion.mandatory && (to & last_rank).is_empty() {
+                    actions.push(Action {
+                        from,
+                        to: bit,
+                        team,
+                        info: NORMAL_PAWN_MOVE,
+                        piece_type,
+                        kind: ActionKind::Move,
+                    });
+                }
+
+                for &promotion_piece_type in self.promotion.pieces {
+                    if !self
+                        .promotion
+                        .can_promote_to(board, promotion_piece_type, team)
+                    {
+                        continue;
+                    }
+
+                    actions.push(Action {
+                        from,
+                        to: bit,
+                        team,
+                        info: promotion_move(promotion_piece_type),
+                        piece_type,
+                        kind: ActionKind::Move,
+                    });
+                }
+            } else {
+                let mut en_passant = false;
+                if let Some(last_move) = board.history.last() {
+                    let conditions = is_en_passant_target(board, &last_move.action)
+                        && (last_move.action.to.abs_diff(bit) == (cols))
+                        && (from.abs_diff(bit) % cols != 0);
+
+                    if conditions {
+                        en_passant = true;
+                    }
+                }
+
+                actions.push(Action {
+                    from,
+                    to: bit,
+                    team,
+                    info: if en_passant {
+                        EN_PASSANT_MOVE
+                    } else {
+                        NORMAL_PAWN_MOVE
+                    },
+                    piece_type,
+                    kind: ActionKind::Move,
+                });
+            }
+        }
+    }
+
     fn make_en_passant_move(
         &self,
         board: &mut Board,
@@ -97,6 +259,19 @@ impl Piece for PawnPiece {
         true
     }
 
+    /// Pawns attack the same squares wherever the other pieces are, even though their moves can be blocked.
+    #[allow(unused_variables)]
+    fn get_attack_dependencies(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        attacks: BitBoard,
+    ) -> BitBoard {
+        BitBoard::new()
+    }
+
     fn generate_lookup_moves(&self, board: &Board, mut from: BitBoard) -> AttackDirections {
         let mut attack_dirs: AttackDirections = vec![];
         let edges = board.state.edges[0];
@@ -118,6 +293,20 @@ impl Piece for PawnPiece {
         PieceSymbol::Char('p')
     }
 
+    fn is_capture(&self, board: &Board, action: &Action) -> bool {
+        if action.info == EN_PASSANT_MOVE {
+            return true;
+        }
+
+        let to = BitBoard::from_lsb(action.to);
+        (to & board.state.all_pieces & !board.state.teams[action.team as usize]).is_set()
+    }
+
+    #[allow(unused_variables)]
+    fn get_promotion(&self, board: &Board, action: &Action) -> Option<PieceType> {
+        action.info.checked_sub(promotion_move(0))
+    }
+
     fn parse_info(&self, board: &Board, info: String) -> u32 {
         if info.is_empty() {
             // TODO: Check for En Passant
@@ -162,7 +351,7 @@ impl Piece for PawnPiece {
         from_bit: u32,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
         to: BitBoard,
     ) -> BitBoard {
         self.get_attack_lookup(board, piece_type).unwrap()[from_bit as usize][team as usize]
@@ -174,24 +363,23 @@ impl Piece for PawnPiece {
         from: BitBoard,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) -> BitBoard {
         let cols = board.state.cols;
         let edges = &board.state.edges[0];
 
-        if mode == ATTACKS_MODE {
+        if mode == GenerationMode::Attacks {
             return self.get_attack_lookup(board, piece_type).unwrap()
                 [from.bitscan_forward() as usize][team as usize];
         }
 
         let mut moves = BitBoard::new();
 
-        let mut capture_requirements = board.state.all_pieces;
-        let mut captures = self.get_attack_lookup(board, piece_type).unwrap()
-            [from.bitscan_forward() as usize][team as usize];
-
         let single_moves = up(&from, 1, cols, team) & !board.state.all_pieces;
-        let first_move = (from & board.state.first_move).is_set();
+        let mut first_move = (from & board.state.first_move).is_set();
+        for &rank in self.double_move_ranks {
+            first_move |= (from & get_rank(board, rank, team)).is_set();
+        }
 
         moves |= single_moves;
 
@@ -200,23 +388,7 @@ impl Piece for PawnPiece {
             moves |= double_moves;
         }
 
-        if let Some(last_move) = board.history.last() {
-            let conditions = last_move.action.piece_type == 0
-                && (last_move.action.to.abs_diff(last_move.action.from) == (2 * (cols)));
-
-            if conditions {
-                capture_requirements |= up(
-                    &BitBoard::from_lsb(last_move.action.from),
-                    1,
-                    cols,
-                    board.get_next_team(team),
-                );
-            }
-        }
-
-        captures &= capture_requirements;
-
-        moves |= captures;
+        moves |= self.get_captures(board, from, piece_type, team);
 
         moves
     }
@@ -361,68 +533,45 @@ impl Piece for PawnPiece {
 
     fn add_actions(
         &self,
-        actions: &mut Vec<Action>,
+        actions: &mut dyn MoveBuffer,
         board: &Board,
         piece_type: usize,
         from: u32,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) {
-        let promotion_rows = board.state.edges[0].bottom | board.state.edges[0].top;
+        let bit_actions = self.get_moves(board, BitBoard::from_lsb(from), piece_type, team, mode)
+            & !board.state.teams[team as usize];
+        self.add_move_actions(actions, board, piece_type, from, team, bit_actions);
+    }
 
+    /// Captures (including en passant) come straight from the pawn's attacks, and only its pushes are split into promotions and quiets.
+    fn add_stage_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        mode: GenerationMode,
+        stage: MoveStage,
+    ) {
         let from_board = BitBoard::from_lsb(from);
-        let bit_actions = self.get_moves(board, from_board, piece_type, team, mode)
+        let captures = self.get_captures(board, from_board, piece_type, team)
             & !board.state.teams[team as usize];
-
-        if bit_actions.is_empty() {
+        if stage == MoveStage::Captures {
+            self.add_move_actions(actions, board, piece_type, from, team, captures);
             return;
         }
 
-        let cols = board.state.cols;
-
-        let piece_types = board.game.pieces.len();
-
-        for bit in bit_actions.iter_one_bits(board.state.squares) {
-            if (BitBoard::from_lsb(bit) & promotion_rows).is_set() {
-                for promotion_piece_type in 0..piece_types {
-                    if promotion_piece_type == 0 {
-                        continue;
-                    }
-                    if promotion_piece_type == 5 {
-                        continue;
-                    }
-                    actions.push(Action {
-                        from,
-                        to: bit,
-                        team,
-                        info: promotion_move(promotion_piece_type),
-                        piece_type,
-                    });
-                }
-            } else {
-                let mut en_passant = false;
-                if let Some(last_move) = board.history.last() {
-                    let conditions = last_move.action.piece_type == 0
-                        && (last_move.action.to.abs_diff(last_move.action.from) == (2 * (cols)))
-                        && (last_move.action.to.abs_diff(bit) == (cols))
-                        && (from.abs_diff(bit) % cols != 0);
-
-                    if conditions {
-                        en_passant = true;
-                    }
-                }
-
-                actions.push(Action {
-                    from,
-                    to: bit,
-                    team,
-                    info: if en_passant {
-                        EN_PASSANT_MOVE
-                    } else {
-                        NORMAL_PAWN_MOVE
-                    },
-                    piece_type,
-                });
+        let pushes = self.get_moves(board, from_board, piece_type, team, mode)
+            & !board.state.all_pieces
+            & !captures;
+        let mut push_actions = MoveList::new();
+        self.add_move_actions(&mut push_actions, board, piece_type, from, team, pushes);
+        for action in push_actions.iter() {
+            if self.get_stage(board, action) == stage {
+                actions.push(*action);
             }
         }
     }
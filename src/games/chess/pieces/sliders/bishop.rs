@@ -1,9 +1,10 @@
 use crate::board::{
-    pieces::{Piece, PieceSymbol},
+    moves::GenerationMode,
+    pieces::{Piece, PieceKind, PieceSymbol},
     AttackDirections, BitBoard, Board, PieceType,
 };
 
-use super::{get_moves_ray, get_ray_attacks};
+use super::{get_moves_ray, get_ray_attacks, get_slider_attacks};
 
 pub struct BishopPiece;
 
@@ -49,6 +50,10 @@ impl Piece for BishopPiece {
         true
     }
 
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Slider
+    }
+
     fn can_move_mask(
         &self,
         board: &Board,
@@ -56,7 +61,7 @@ impl Piece for BishopPiece {
         from_bit: u32,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
         to: BitBoard,
     ) -> BitBoard {
         let lookup = self
@@ -85,18 +90,12 @@ impl Piece for BishopPiece {
         from: BitBoard,
         piece_type: usize,
         team: u32,
-        mode: u32,
+        mode: GenerationMode,
     ) -> BitBoard {
         let lookup = self
             .get_attack_lookup(board, piece_type)
             .expect("Could not find the queen attack lookup.");
-        let mut attacks = BitBoard::new();
-
         let from_bit = from.bitscan_forward() as usize;
-        for dir in 0..4 {
-            attacks |= get_ray_attacks(board, from, from_bit, dir, &lookup);
-        }
-
-        attacks
+        get_slider_attacks(board, from, from_bit, 4, &lookup)
     }
 }
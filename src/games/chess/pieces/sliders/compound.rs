@@ -0,0 +1,93 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceKind, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::KnightPiece,
+};
+
+use super::{get_ray_attacks, get_slider_attacks};
+
+/// A piece that moves like both a four-way slider and a knight, like the archbishop (a bishop and a knight, sometimes called the princess or cardinal) or the chancellor (a rook and a knight, sometimes called the empress or marshal.)
+pub struct KnightCompoundPiece {
+    /// The slider whose four rays this piece moves along, like `BishopPiece` or `RookPiece`.
+    pub slider: &'static dyn Piece,
+    pub symbol: char,
+}
+
+impl Piece for KnightCompoundPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char(self.symbol)
+    }
+
+    /// The first four slots are the slider's rays, and the sixth slot is the knight's moves.
+    /// The fifth slot combines all of them.
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let mut lookups = self.slider.generate_lookup_moves(board, from);
+        let knight_moves = KnightPiece.generate_lookup_moves(board, from)[0];
+        lookups[4] |= knight_moves;
+        lookups.push(knight_moves);
+        lookups
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    /// The knight's leaps never depend on the other pieces, so only the slider's rays can be blocked.
+    fn get_piece_kind(&self) -> PieceKind {
+        PieceKind::Slider
+    }
+
+    #[allow(unused_variables)]
+    fn can_move_mask(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        from_bit: u32,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+        to: BitBoard,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the compound piece attack lookup.");
+
+        let from_bit = from_bit as usize;
+        if (lookup[from_bit][4] & to).is_empty() {
+            return BitBoard::new();
+        }
+
+        if (lookup[from_bit][5] & to).is_set() {
+            return lookup[from_bit][5];
+        }
+
+        for dir in 0..4 {
+            let ray = get_ray_attacks(board, from, from_bit, dir, lookup);
+            if (ray & to).is_set() {
+                return ray;
+            }
+        }
+
+        BitBoard::new()
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the compound piece attack lookup.");
+
+        let from_bit = from.bitscan_forward() as usize;
+        lookup[from_bit][5] | get_slider_attacks(board, from, from_bit, 4, lookup)
+    }
+}
@@ -1,9 +1,11 @@
 mod bishop;
+mod compound;
 mod queen;
 mod rook;
 mod util;
 
 pub use bishop::*;
+pub use compound::*;
 pub use queen::*;
 pub use rook::*;
 pub use util::*;
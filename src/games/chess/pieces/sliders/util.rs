@@ -31,11 +31,42 @@ pub fn get_ray_attacks(
     from_bit: usize,
     dir: u32,
     ray_attacks: &AttackLookup,
+) -> BitBoard {
+    get_blocked_ray(from, from_bit, dir, ray_attacks, board.state.all_pieces)
+}
+
+/// Gets every square attacked along the first `rays` directions of a slider, with a single lookup if it has magics for `from_bit` (see `AttackLookup::get_slider_attacks`.)
+pub fn get_slider_attacks(
+    board: &Board,
+    from: BitBoard,
+    from_bit: usize,
+    rays: u32,
+    ray_attacks: &AttackLookup,
+) -> BitBoard {
+    if let Some(attacks) = ray_attacks.get_slider_attacks(from_bit, board.state.all_pieces) {
+        return attacks;
+    }
+
+    let mut attacks = BitBoard::new();
+    for dir in 0..rays {
+        attacks |= get_ray_attacks(board, from, from_bit, dir, ray_attacks);
+    }
+    attacks
+}
+
+/// Gets the ray in the given direction up to (and including) the first square in `blockers`.
+/// `get_ray_attacks` stops at the first piece, but games like reversi need rays that stop at other squares instead.
+pub fn get_blocked_ray(
+    from: BitBoard,
+    from_bit: usize,
+    dir: u32,
+    ray_attacks: &AttackLookup,
+    blockers: BitBoard,
 ) -> BitBoard {
     let dir_usize = dir as usize;
     let mut attacks = ray_attacks[from_bit][dir_usize];
     let mut blocker = attacks;
-    blocker &= board.state.all_pieces;
+    blocker &= blockers;
     if blocker.is_set() {
         let square = if from < blocker {
             blocker.bitscan_forward()
@@ -47,3 +78,38 @@ pub fn get_ray_attacks(
     }
     return attacks;
 }
+
+/// Hoppers (like the xiangqi cannon) capture by jumping over exactly one piece (the screen) along a ray, landing on the first piece behind it.
+/// This returns that piece's square regardless of its team, or an empty board if there's no screen or nothing behind it.
+pub fn get_hopper_attacks(
+    board: &Board,
+    from: BitBoard,
+    from_bit: usize,
+    dir: u32,
+    ray_attacks: &AttackLookup,
+) -> BitBoard {
+    let dir_usize = dir as usize;
+    let screens = ray_attacks[from_bit][dir_usize] & board.state.all_pieces;
+    if screens.is_empty() {
+        return BitBoard::new();
+    }
+
+    let forward = from < screens;
+    let screen = if forward {
+        screens.bitscan_forward()
+    } else {
+        screens.bitscan_reverse()
+    };
+
+    let targets = ray_attacks[screen as usize][dir_usize] & board.state.all_pieces;
+    if targets.is_empty() {
+        return BitBoard::new();
+    }
+
+    let target = if forward {
+        targets.bitscan_forward()
+    } else {
+        targets.bitscan_reverse()
+    };
+    BitBoard::from_lsb(target)
+}
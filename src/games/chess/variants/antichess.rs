@@ -0,0 +1,123 @@
+use crate::{
+    board::{
+        actions::Action,
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::{ForcedCaptures, Game, GameResults, NoRestrictions, Resolution},
+        pieces::Piece,
+        Board,
+    },
+    games::chess::{
+        pieces::{PawnPiece, PromotionRules},
+        ChessCastlingRights, ChessEnPassant, ChessPostProcess, BISHOP, KING, KNIGHT, QUEEN, ROOK,
+    },
+};
+
+/// In antichess, you win by losing all of your pieces or by being stalemated.
+pub struct AntichessResolution;
+impl Resolution for AntichessResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let current_team = board.state.moving_team;
+        if board.state.teams[current_team as usize].is_empty() || legal_moves.is_empty() {
+            return GameResults::Win(current_team);
+        }
+
+        GameResults::Ongoing
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(AntichessResolution)
+    }
+}
+
+/// The king is a normal piece in antichess, so pawns are able to promote to it.
+const ANTICHESS_PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 1,
+        mandatory: true,
+        pieces: &[1, 2, 3, 4, 5],
+        limits: None,
+    },
+    double_move_ranks: &[],
+};
+
+pub struct Antichess;
+
+impl Antichess {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![ANTICHESS_PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            piece_values: vec![100, 300, 300, 500, 900, 300],
+            move_restrictions: Box::new(ForcedCaptures(Box::new(NoRestrictions))),
+            resolution: Box::new(AntichessResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    // Castling isn't allowed in antichess, so this should always be `-`.
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::chess::Antichess,
+    };
+
+    #[test]
+    fn startpos() {
+        let antichess = Antichess::create();
+        let mut board = Board::new(
+            &antichess,
+            (8, 8),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1",
+        );
+
+        board.assert_perfts([20, 400, 8067, 153299, 2732672]);
+    }
+
+    #[test]
+    fn forced_capture() {
+        let antichess = Antichess::create();
+        let mut board = Board::new(&antichess, (8, 8), "8/8/8/3p4/4P3/8/8/K7 w - - 0 1");
+
+        let moves = board.generate_legal_moves(GenerationMode::Normal);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(board.encode_action(&moves[0]), "e4d5");
+    }
+
+    #[test]
+    fn king_promotion() {
+        let antichess = Antichess::create();
+        let mut board = Board::new(&antichess, (8, 8), "8/4P3/8/8/8/8/8/7k w - - 0 1");
+
+        let moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        assert!(moves.contains(&"e7e8k".to_string()));
+    }
+
+    #[test]
+    fn losing_all_pieces_wins() {
+        let antichess = Antichess::create();
+        let mut board = Board::new(&antichess, (8, 8), "8/8/8/8/8/8/8/7k w - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    board::{
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::Game,
+        pieces::Piece,
+    },
+    games::chess::{
+        pieces::{BishopPiece, KnightCompoundPiece, PawnPiece, PromotionRules, RookPiece},
+        ChessCastlingRights, ChessEnPassant, ChessMoveRestrictions, ChessPostProcess,
+        ChessResolution, BISHOP, KING, KNIGHT, QUEEN, ROOK,
+    },
+};
+
+/// The archbishop moves like both a bishop and a knight.
+pub(crate) const ARCHBISHOP: &dyn Piece = &KnightCompoundPiece {
+    slider: &BishopPiece,
+    symbol: 'a',
+};
+/// The chancellor moves like both a rook and a knight.
+pub(crate) const CHANCELLOR: &dyn Piece = &KnightCompoundPiece {
+    slider: &RookPiece,
+    symbol: 'c',
+};
+
+/// Pawns can also promote to the archbishop and chancellor on 10x8 boards.
+const CAPABLANCA_PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 1,
+        mandatory: true,
+        pieces: &[1, 2, 3, 4, 6, 7],
+        limits: None,
+    },
+    double_move_ranks: &[],
+};
+
+/// Capablanca chess is played on a 10x8 board, with an archbishop (`a`) and chancellor (`c`) added to each side.
+///
+/// Its starting position is `rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1`.
+pub struct Capablanca;
+
+impl Capablanca {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![
+                CAPABLANCA_PAWN,
+                KNIGHT,
+                BISHOP,
+                ROOK,
+                QUEEN,
+                KING,
+                ARCHBISHOP,
+                CHANCELLOR,
+            ],
+            piece_values: vec![100, 300, 300, 500, 900, 20000, 800, 850],
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(ChessResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+/// Gothic chess has the same rules as Capablanca chess, and only differs in its starting position.
+///
+/// Its starting position is `rnbqckabnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNBQCKABNR w KQkq - 0 1`.
+pub struct Gothic;
+
+impl Gothic {
+    pub fn create() -> Game {
+        Capablanca::create()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::Board,
+        games::chess::{Capablanca, Gothic},
+    };
+
+    #[test]
+    fn capablanca_startpos() {
+        let capablanca = Capablanca::create();
+        let mut board = Board::new(
+            &capablanca,
+            (8, 10),
+            "rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1",
+        );
+
+        board.assert_perfts([28, 784, 25228, 805128]);
+    }
+
+    #[test]
+    fn gothic_startpos() {
+        let gothic = Gothic::create();
+        let mut board = Board::new(
+            &gothic,
+            (8, 10),
+            "rnbqckabnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNBQCKABNR w KQkq - 0 1",
+        );
+
+        board.assert_perfts([28, 784, 25283, 808984]);
+    }
+
+    #[test]
+    fn castling() {
+        let capablanca = Capablanca::create();
+        let mut board = Board::new(
+            &capablanca,
+            (8, 10),
+            "r1b2k3r/10/10/10/10/10/10/R4K1n1R w KQkq - 0 1",
+        );
+
+        board.assert_perfts([25, 818, 19316]);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    board::{
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::Game,
+        pieces::Piece,
+    },
+    games::chess::{
+        pieces::{PawnPiece, PromotionRules},
+        ChessCastlingRights, ChessEnPassant, ChessMoveRestrictions, ChessPostProcess,
+        ChessResolution, ARCHBISHOP, BISHOP, CHANCELLOR, KING, KNIGHT, QUEEN, ROOK,
+    },
+};
+
+/// Grand Chess pawns start on the third rank, and can promote on any of the last three ranks (only being forced to on the last rank.)
+/// They can only promote to pieces that have already been captured, so a team can never have more than its starting amount of any piece.
+const GRAND_PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 3,
+        mandatory: false,
+        pieces: &[4, 7, 6, 3, 2, 1],
+        limits: Some(&[0, 2, 2, 2, 1, 0, 1, 1]),
+    },
+    double_move_ranks: &[3],
+};
+
+/// Grand Chess is played on a 10x10 board with an archbishop (`a`) and chancellor (`c`), and has no castling.
+///
+/// Its starting position is `r8r/1nbqkcabn1/pppppppppp/10/10/10/10/PPPPPPPPPP/1NBQKCABN1/R8R w - - 0 1`.
+pub struct GrandChess;
+
+impl GrandChess {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![
+                GRAND_PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING, ARCHBISHOP, CHANCELLOR,
+            ],
+            piece_values: vec![100, 300, 300, 500, 900, 20000, 800, 850],
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(ChessResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    // Castling isn't allowed in Grand Chess, so this should always be `-`.
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{moves::GenerationMode, Board},
+        games::chess::GrandChess,
+    };
+
+    #[test]
+    fn startpos() {
+        let grand_chess = GrandChess::create();
+        let mut board = Board::new(
+            &grand_chess,
+            (10, 10),
+            "r8r/1nbqkcabn1/pppppppppp/10/10/10/10/PPPPPPPPPP/1NBQKCABN1/R8R w - - 0 1",
+        );
+
+        board.assert_perfts([65, 4225, 259514]);
+    }
+
+    #[test]
+    fn captured_piece_promotion() {
+        let grand_chess = GrandChess::create();
+        let mut board = Board::new(
+            &grand_chess,
+            (10, 10),
+            "4k5/P9/2P7/10/10/10/10/10/2QCABB3/R3K3RN w - - 0 1",
+        );
+
+        let moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .filter(|action| action.starts_with("a9") || action.starts_with("c8"))
+            .collect::<Vec<_>>();
+        assert_eq!(moves, vec!["a9a10n", "c8c9", "c8c9n"]);
+
+        board.assert_perfts([101, 283, 28472]);
+    }
+
+    #[test]
+    fn no_captured_pieces() {
+        let grand_chess = GrandChess::create();
+        let mut board = Board::new(
+            &grand_chess,
+            (10, 10),
+            "r3k4r/P8P/10/10/3p6/10/4P5/10/QCARBN4/1NRB2K3 w - - 0 1",
+        );
+
+        board.assert_perfts([60, 800, 48502]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    board::{
+        actions::Action,
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::{Game, GameResults, Resolution},
+        pieces::Piece,
+        Board,
+    },
+    games::chess::{
+        pieces::{PawnPiece, PromotionRules},
+        ChessCastlingRights, ChessEnPassant, ChessMoveRestrictions, ChessPostProcess,
+        ChessResolution, BISHOP, CHESS_PIECE_VALUES, KING, KNIGHT, QUEEN, ROOK,
+    },
+};
+
+/// In horde, a team loses once all of their pieces have been captured.
+/// Otherwise, the game ends just like chess does, which means a horde that's stalemated is a draw.
+pub struct HordeResolution;
+impl Resolution for HordeResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        for team in 0..board.state.teams.len() {
+            if board.state.teams[team].is_empty() {
+                return GameResults::Win(board.get_next_team(team as u32));
+            }
+        }
+
+        ChessResolution.resolve(board, legal_moves)
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(HordeResolution)
+    }
+}
+
+/// Horde pawns on the first rank are allowed to double move, just like the ones on the second rank.
+const HORDE_PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 1,
+        mandatory: true,
+        pieces: &[1, 2, 3, 4],
+        limits: None,
+    },
+    double_move_ranks: &[1, 2],
+};
+
+pub struct Horde;
+
+impl Horde {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![HORDE_PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            piece_values: CHESS_PIECE_VALUES.to_vec(),
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(HordeResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, Board},
+        games::chess::Horde,
+    };
+
+    #[test]
+    fn startpos() {
+        let horde = Horde::create();
+        let mut board = Board::new(
+            &horde,
+            (8, 8),
+            "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1",
+        );
+
+        board.assert_perfts([8, 128, 1274, 23310, 265223]);
+    }
+
+    #[test]
+    fn open_flank() {
+        let horde = Horde::create();
+        let mut board = Board::new(
+            &horde,
+            (8, 8),
+            "4k3/pp4q1/3P2p1/8/P3PP2/PPP2r2/PPP5/PPPP4 b - - 0 1",
+        );
+
+        board.assert_perfts([30, 241, 6633, 56539]);
+    }
+
+    #[test]
+    fn en_passant() {
+        let horde = Horde::create();
+        let mut board = Board::new(
+            &horde,
+            (8, 8),
+            "k7/5p2/4p2P/3p2P1/2p2P2/1p2P2P/p2P2P1/2P2P2 w - - 0 1",
+        );
+
+        board.assert_perfts([13, 172, 2205, 33781]);
+    }
+
+    #[test]
+    fn losing_all_pieces() {
+        let horde = Horde::create();
+        let mut board = Board::new(&horde, (8, 8), "4k3/8/8/8/8/8/8/8 w - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Win(1));
+    }
+}
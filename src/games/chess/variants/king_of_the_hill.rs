@@ -0,0 +1,142 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+    game::{Game, GameResults, Resolution},
+    BitBoard, Board,
+};
+
+use crate::games::chess::{
+    ChessCastlingRights, ChessEnPassant, ChessMoveRestrictions, ChessPostProcess, ChessResolution,
+    BISHOP, CHESS_PIECE_VALUES, KING, KNIGHT, PAWN, QUEEN, ROOK,
+};
+
+/// The center rows (or columns) of a side with `length` squares: the middle two if it's even, and the middle one if it's odd.
+fn get_center(length: u32) -> std::ops::RangeInclusive<u32> {
+    if length.is_multiple_of(2) {
+        (length / 2).saturating_sub(1)..=(length / 2)
+    } else {
+        (length / 2)..=(length / 2)
+    }
+}
+
+/// The hill is the center of the board (`d4`, `e4`, `d5` and `e5` on a normal chessboard.)
+/// Boards with an odd number of rows or columns only have a single center row or column.
+pub fn get_hill(board: &Board) -> BitBoard {
+    let cols = board.state.cols;
+    let mut hill = BitBoard::new();
+    for row in get_center(board.state.rows) {
+        for col in get_center(cols) {
+            hill |= BitBoard::from_lsb(row * cols + col);
+        }
+    }
+    hill
+}
+
+/// A team wins once their king reaches the hill.
+pub struct KingOfTheHillResolution;
+impl Resolution for KingOfTheHillResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let hill = get_hill(board);
+        let kings = board.state.pieces[5] & hill;
+        if kings.is_set() {
+            for team in 0..board.state.teams.len() {
+                if (kings & board.state.teams[team]).is_set() {
+                    return GameResults::Win(team as u32);
+                }
+            }
+        }
+
+        ChessResolution.resolve(board, legal_moves)
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(KingOfTheHillResolution)
+    }
+}
+
+pub struct KingOfTheHill;
+
+impl KingOfTheHill {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            piece_values: CHESS_PIECE_VALUES.to_vec(),
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(KingOfTheHillResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::chess::{get_hill, KingOfTheHill},
+    };
+
+    #[test]
+    fn startpos() {
+        let king_of_the_hill = KingOfTheHill::create();
+        let mut board = Board::new(
+            &king_of_the_hill,
+            (8, 8),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        );
+
+        board.assert_perfts([20, 400, 8902, 197281]);
+    }
+
+    #[test]
+    fn hill() {
+        let king_of_the_hill = KingOfTheHill::create();
+        let hill = |board: &Board| {
+            get_hill(board)
+                .iter_one_bits(board.state.squares)
+                .map(|square| board.encode_position(square))
+                .collect::<Vec<_>>()
+        };
+
+        let mut board = Board::new(&king_of_the_hill, (8, 8), "4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        assert_eq!(hill(&board), vec!["d5", "e5", "d4", "e4"]);
+
+        board = Board::new(
+            &king_of_the_hill,
+            (10, 10),
+            "4k5/10/10/10/10/10/10/10/10/4K5 w - - 0 1",
+        );
+        assert_eq!(hill(&board), vec!["e6", "f6", "e5", "f5"]);
+
+        // Odd sides only have one center row or column.
+        board = Board::new(&king_of_the_hill, (5, 5), "k4/5/5/5/4K w - - 0 1");
+        assert_eq!(hill(&board), vec!["c3"]);
+    }
+
+    #[test]
+    fn reaching_the_hill_wins() {
+        let king_of_the_hill = KingOfTheHill::create();
+        let mut board = Board::new(&king_of_the_hill, (8, 8), "k7/8/8/8/8/4K3/8/8 w - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+        let action = board.decode_action("e3e4", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+        assert_eq!(board.perft(1, true, GenerationMode::Normal), 0);
+    }
+}
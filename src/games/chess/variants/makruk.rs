@@ -0,0 +1,283 @@
+use crate::{
+    board::{
+        actions::Action,
+        fen::{
+            FenArgument, FenDecodeError, FenFullMoves, FenOptions, FenState, FenSubMoves,
+            FenTeamArgument, FenUnused, PostProcess,
+        },
+        game::{CustomStateUpdate, Game, GameResults, Resolution},
+        pieces::Piece,
+        BitBoard, Board, CustomState,
+    },
+    games::chess::{
+        pieces::{FerzPiece, KhonPiece, PawnPiece, PromotionRules},
+        ChessMoveRestrictions, ChessResolution, KING, KNIGHT, ROOK,
+    },
+};
+
+const KHON: &dyn Piece = &KhonPiece;
+const MET: &dyn Piece = &FerzPiece { symbol: 'm' };
+
+/// Makruk pawns (bia) start on the third rank, and must promote to a met as soon as they reach the sixth.
+const MAKRUK_PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 3,
+        mandatory: true,
+        pieces: &[4],
+        limits: None,
+    },
+    double_move_ranks: &[],
+};
+
+/// Pawns never double move and kings never castle in makruk or shatranj, so no piece keeps its first move.
+pub struct NoFirstMovesPostProcess;
+impl PostProcess for NoFirstMovesPostProcess {
+    fn apply(&self, board: &mut Board) {
+        board.state.first_move = BitBoard::new();
+    }
+
+    fn duplicate(&self) -> Box<dyn PostProcess> {
+        Box::new(NoFirstMovesPostProcess)
+    }
+}
+
+/// The team whose pieces are counting down a bare enemy king, once the pieces' honour counting applies.
+fn get_counting_team(board: &Board) -> Option<usize> {
+    let kings = board.state.pieces[5];
+    (0..board.state.teams.len()).find_map(|team| {
+        let enemy_team = board.get_next_team(team as u32) as usize;
+        let bare_king = (board.state.teams[team] & !kings).is_empty();
+        let has_pieces = (board.state.teams[enemy_team] & !kings).is_set();
+        (bare_king && has_pieces).then_some(enemy_team)
+    })
+}
+
+/// How many plies the stronger team has to win, once the counting rules apply.
+/// Once there are no pawns left, the board's honour counting gives 64 moves. If one team is also down to a bare king, the pieces' honour counting depends on what the other team has left.
+pub fn get_counting_limit(board: &Board) -> u32 {
+    if board.state.pieces[0].is_set() {
+        return 0;
+    }
+
+    let Some(enemy_team) = get_counting_team(board) else {
+        return 128;
+    };
+    let count = |piece_type: usize| {
+        board
+            .state
+            .get_piece_team_board(piece_type, enemy_team)
+            .count_ones()
+    };
+    match (count(3), count(2), count(1)) {
+        (2.., _, _) => 16,
+        (1, _, _) => 32,
+        (_, 2.., _) => 44,
+        (_, _, 2..) => 64,
+        (_, 1, _) => 88,
+        _ => 128,
+    }
+}
+
+/// The ply a count starts from: the board's honour counts from the start, but the pieces' honour counts on from the number of pieces left on the board (two plies for each of them.)
+pub fn get_counting_start(board: &Board) -> u32 {
+    if board.state.pieces[0].is_empty() && get_counting_team(board).is_some() {
+        board.state.all_pieces.count_ones() * 2
+    } else {
+        0
+    }
+}
+
+/// Makruk stores how many plies have been counted, and the ply limit of the current count in `board.state.custom`.
+/// The count starts over (see `get_counting_start`) whenever its limit changes (eg. when the last pawn is captured, or when a team is left with a bare king.)
+pub struct MakrukCountingState;
+impl CustomStateUpdate for MakrukCountingState {
+    /// `MakrukCounting` sets the count up for the position once the FEN is loaded.
+    fn initial(&self) -> CustomState {
+        let mut custom = CustomState::new();
+        custom.push(0);
+        custom.push(0);
+        custom
+    }
+
+    #[allow(unused_variables)]
+    fn update(&self, board: &mut Board, action: &Action) {
+        let limit = get_counting_limit(board);
+        if limit == board.state.custom[1] {
+            board.state.custom[0] += 1;
+        } else {
+            board.state.custom[0] = get_counting_start(board);
+            board.state.custom[1] = limit;
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn CustomStateUpdate> {
+        Box::new(MakrukCountingState)
+    }
+}
+
+/// The plies counted so far, or `-` to start counting from the position (which is also how it's written when nothing is being counted.)
+pub struct MakrukCounting;
+impl FenArgument for MakrukCounting {
+    fn encode(&self, board: &Board) -> String {
+        if board.state.custom[1] == 0 {
+            "-".to_string()
+        } else {
+            board.state.custom[0].to_string()
+        }
+    }
+
+    fn decode(&self, board: &mut Board, arg: &str) -> Result<(), FenDecodeError> {
+        let plies = if arg == "-" {
+            get_counting_start(board)
+        } else {
+            arg.parse::<u32>().map_err(|_| {
+                FenDecodeError::InvalidArgument(format!(
+                    "'{arg}' is not a valid count, which should be a positive integer or `-`."
+                ))
+            })?
+        };
+
+        board.state.custom[0] = plies;
+        board.state.custom[1] = get_counting_limit(board);
+        Ok(())
+    }
+
+    fn duplicate(&self) -> Box<dyn FenArgument> {
+        Box::new(MakrukCounting)
+    }
+}
+
+/// Checkmate wins and stalemate draws just like chess, but the game is also drawn once the count runs out.
+pub struct MakrukResolution;
+impl Resolution for MakrukResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let results = ChessResolution.resolve(board, legal_moves);
+        if results != GameResults::Ongoing {
+            return results;
+        }
+
+        let (plies, limit) = (board.state.custom[0], board.state.custom[1]);
+        if limit > 0 && plies >= limit {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(MakrukResolution)
+    }
+}
+
+/// Makruk (Thai chess) replaces the bishop with the khon (`s`) and the queen with the met (`m`), and doesn't have castling or pawn double moves.
+///
+/// Its starting position is `rnsmksnr/8/pppppppp/8/8/PPPPPPPP/8/RNSKMSNR w - - 0 1 -`.
+pub struct Makruk;
+
+impl Makruk {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![MAKRUK_PAWN, KNIGHT, KHON, ROOK, MET, KING],
+            piece_values: vec![100, 300, 250, 500, 200, 20000],
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(MakrukResolution),
+            custom_state: Some(Box::new(MakrukCountingState)),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(FenUnused)),
+                    ("en passant".to_string(), Box::new(FenUnused)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                    ("counting".to_string(), Box::new(MakrukCounting)),
+                ],
+                post_process: Box::new(NoFirstMovesPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::chess::Makruk,
+    };
+
+    #[test]
+    fn startpos() {
+        let makruk = Makruk::create();
+        let mut board = Board::new(
+            &makruk,
+            (8, 8),
+            "rnsmksnr/8/pppppppp/8/8/PPPPPPPP/8/RNSKMSNR w - - 0 1 -",
+        );
+
+        board.assert_perfts([23, 529, 12012, 273026]);
+    }
+
+    #[test]
+    fn sixth_rank_promotion() {
+        let makruk = Makruk::create();
+        let mut board = Board::new(&makruk, (8, 8), "4k3/8/8/P2p4/8/2P2p2/3K4/8 w - - 0 1 -");
+
+        board.assert_perfts([8, 57, 433, 3810]);
+
+        let action = board
+            .decode_action("a5a6m", GenerationMode::Normal)
+            .unwrap();
+        board.make_move(&action);
+        assert!(board
+            .decode_action("d5d4", GenerationMode::Normal)
+            .is_some());
+    }
+
+    #[test]
+    fn khon_and_met() {
+        let makruk = Makruk::create();
+        let mut board = Board::new(&makruk, (8, 8), "8/8/3k4/8/1p6/3MS3/2KR4/8 w - - 0 1 -");
+
+        board.assert_perfts([17, 137, 2703]);
+    }
+
+    #[test]
+    fn counting_draw() {
+        let makruk = Makruk::create();
+        let mut board = Board::new(&makruk, (8, 8), "8/8/8/3k4/8/8/8/K6R w - - 0 1 -");
+
+        // A lone rook has 16 moves (32 plies) to checkmate a bare king, counting on from the 3 pieces left on the board.
+        assert_eq!(board.to_fen(), "8/8/8/3k4/8/8/8/K6R w - - 0 1 6");
+        let moves = ["h1h2", "d5d6", "h2h1", "d6d5"];
+        for ply in 0..26 {
+            assert_eq!(board.resolve(), GameResults::Ongoing);
+            let action = board
+                .decode_action(moves[ply % 4], GenerationMode::Normal)
+                .unwrap();
+            board.make_move(&action);
+        }
+        assert_eq!(board.resolve(), GameResults::Draw);
+
+        board.undo_move().unwrap();
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+
+        // Counts carry over through the FEN.
+        board = Board::new(&makruk, (8, 8), "8/8/8/3k4/8/8/8/K6R w - - 0 1 31");
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+        let action = board.decode_action("h1h2", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Draw);
+
+        // Once a pawn is captured, leaving a bare king, the count starts from the 4 pieces left.
+        board = Board::new(&makruk, (8, 8), "8/8/8/3k4/8/3p4/8/K2R3R w - - 0 1 -");
+        assert_eq!(board.to_fen(), "8/8/8/3k4/8/3p4/8/K2R3R w - - 0 1 -");
+        let action = board.decode_action("d1d3", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert!(board.to_fen().ends_with(" 8"));
+    }
+}
@@ -0,0 +1,19 @@
+mod antichess;
+mod capablanca;
+mod grand;
+mod horde;
+mod king_of_the_hill;
+mod makruk;
+mod racing_kings;
+mod shatranj;
+mod three_check;
+
+pub use antichess::*;
+pub use capablanca::*;
+pub use grand::*;
+pub use horde::*;
+pub use king_of_the_hill::*;
+pub use makruk::*;
+pub use racing_kings::*;
+pub use shatranj::*;
+pub use three_check::*;
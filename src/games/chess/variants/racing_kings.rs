@@ -0,0 +1,167 @@
+use crate::{
+    board::{
+        actions::Action,
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument},
+        game::{Game, GameResults, MoveRestrictions, Resolution},
+        moves::GenerationMode,
+        Board,
+    },
+    games::chess::{
+        pieces::get_rank, ChessCastlingRights, ChessEnPassant, ChessPostProcess, BISHOP,
+        CHESS_PIECE_VALUES, KING, KNIGHT, PAWN, QUEEN, ROOK,
+    },
+};
+
+/// Neither team is ever allowed to be in check, so a move is illegal both if it leaves your own king in check, or if it gives check.
+pub struct RacingKingsMoveRestrictions;
+impl MoveRestrictions for RacingKingsMoveRestrictions {
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        let current_team = board.state.moving_team;
+
+        board.make_move(action);
+        let enemy_team = board.state.moving_team;
+        let kings = board.state.pieces[5];
+        let own_king = board.state.teams[current_team as usize] & kings;
+        let enemy_king = board.state.teams[enemy_team as usize] & kings;
+        let in_check = (board.get_move_mask(enemy_team, GenerationMode::Attacks) & own_king)
+            .is_set()
+            || (board.get_move_mask(current_team, GenerationMode::Attacks) & enemy_king).is_set();
+        board.undo_move().unwrap();
+        !in_check
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(RacingKingsMoveRestrictions)
+    }
+}
+
+/// The first king to reach the eighth rank wins.
+/// If white gets there first, black still has one more move to reach it as well, which makes the game a draw.
+pub struct RacingKingsResolution;
+impl Resolution for RacingKingsResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let goal = get_rank(board, 8, 0);
+        let kings = board.state.pieces[5];
+        let white_king = kings & board.state.teams[0];
+        let black_king = kings & board.state.teams[1];
+
+        let white_finished = (white_king & goal).is_set();
+        let black_finished = (black_king & goal).is_set();
+
+        match (white_finished, black_finished) {
+            (true, true) => GameResults::Draw,
+            (false, true) => GameResults::Win(1),
+            (true, false) => {
+                if board.state.moving_team == 0 || black_king.is_empty() {
+                    return GameResults::Win(0);
+                }
+
+                let black_king = black_king.bitscan_forward() as usize;
+                let targets = board.attack_lookup[5][black_king][0] & goal & !board.state.teams[1];
+                let attacks = board.get_move_mask(0, GenerationMode::Attacks);
+                if (targets & !attacks).is_set() {
+                    GameResults::Ongoing
+                } else {
+                    GameResults::Win(0)
+                }
+            }
+            (false, false) => {
+                if legal_moves.is_empty() {
+                    GameResults::Draw
+                } else {
+                    GameResults::Ongoing
+                }
+            }
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(RacingKingsResolution)
+    }
+}
+
+pub struct RacingKings;
+
+impl RacingKings {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            piece_values: CHESS_PIECE_VALUES.to_vec(),
+            move_restrictions: Box::new(RacingKingsMoveRestrictions),
+            resolution: Box::new(RacingKingsResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    // Castling isn't allowed in racing kings, so this should always be `-`.
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::chess::RacingKings,
+    };
+
+    #[test]
+    fn startpos() {
+        let racing_kings = RacingKings::create();
+        let mut board = Board::new(
+            &racing_kings,
+            (8, 8),
+            "8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1",
+        );
+
+        board.assert_perfts([21, 421, 11264, 296242]);
+    }
+
+    #[test]
+    fn no_checks() {
+        let racing_kings = RacingKings::create();
+        let mut board = Board::new(&racing_kings, (8, 8), "8/8/8/8/8/k7/8/6RK w - - 0 1");
+
+        let moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        assert!(!moves.contains(&"g1a1".to_string()));
+        assert!(!moves.contains(&"g1g3".to_string()));
+        assert!(moves.contains(&"g1g2".to_string()));
+    }
+
+    #[test]
+    fn black_can_draw() {
+        let racing_kings = RacingKings::create();
+        let mut board = Board::new(&racing_kings, (8, 8), "6K1/k7/8/8/8/8/8/8 b - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+        let action = board.decode_action("a7a8", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Draw);
+    }
+
+    #[test]
+    fn white_wins() {
+        let racing_kings = RacingKings::create();
+        let mut board = Board::new(&racing_kings, (8, 8), "6K1/8/k7/8/8/8/8/8 b - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Win(0));
+        assert_eq!(board.perft(1, true, GenerationMode::Normal), 0);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    board::{
+        actions::Action,
+        fen::{FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument, FenUnused},
+        game::{Game, GameResults, Resolution},
+        pieces::Piece,
+        BitBoard, Board,
+    },
+    games::chess::{
+        pieces::{AlfilPiece, FerzPiece, PawnPiece, PromotionRules},
+        ChessMoveRestrictions, NoFirstMovesPostProcess, KING, KNIGHT, ROOK,
+    },
+};
+
+const ALFIL: &dyn Piece = &AlfilPiece;
+const FERZ: &dyn Piece = &FerzPiece { symbol: 'q' };
+
+/// Shatranj pawns can only promote to a ferz.
+const SHATRANJ_PAWN: &dyn Piece = &PawnPiece {
+    promotion: PromotionRules {
+        zone: 1,
+        mandatory: true,
+        pieces: &[4],
+        limits: None,
+    },
+    double_move_ranks: &[],
+};
+
+/// Both checkmating and stalemating your opponent wins in shatranj.
+/// Capturing every piece but the enemy king (baring it) also wins, unless they can bare your king right back, which draws instead.
+pub struct ShatranjResolution;
+impl Resolution for ShatranjResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let current_team = board.state.moving_team;
+        let enemy_team = board.get_next_team(current_team);
+
+        if legal_moves.is_empty() {
+            return GameResults::Win(enemy_team);
+        }
+
+        let kings = board.state.pieces[5];
+        let pieces = board.state.teams[current_team as usize] & !kings;
+        let enemy_pieces = board.state.teams[enemy_team as usize] & !kings;
+
+        match (pieces.is_empty(), enemy_pieces.count_ones()) {
+            (true, 0) => GameResults::Draw,
+            (true, 1) => {
+                let can_bare_back = legal_moves
+                    .iter()
+                    .any(|action| (BitBoard::from_lsb(action.to) & enemy_pieces).is_set());
+                if can_bare_back {
+                    GameResults::Ongoing
+                } else {
+                    GameResults::Win(enemy_team)
+                }
+            }
+            (true, _) => GameResults::Win(enemy_team),
+            (false, _) => GameResults::Ongoing,
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(ShatranjResolution)
+    }
+}
+
+/// Shatranj (the Persian ancestor of chess) replaces the bishop with the alfil (`b`) and the queen with the ferz (`q`), and doesn't have castling or pawn double moves.
+///
+/// Its starting position is `rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1`.
+pub struct Shatranj;
+
+impl Shatranj {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![SHATRANJ_PAWN, KNIGHT, ALFIL, ROOK, FERZ, KING],
+            piece_values: vec![100, 300, 150, 500, 150, 20000],
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(ShatranjResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(FenUnused)),
+                    ("en passant".to_string(), Box::new(FenUnused)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(NoFirstMovesPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::chess::Shatranj,
+    };
+
+    #[test]
+    fn startpos() {
+        let shatranj = Shatranj::create();
+        let mut board = Board::new(
+            &shatranj,
+            (8, 8),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1",
+        );
+
+        board.assert_perfts([16, 256, 4176, 68122]);
+    }
+
+    #[test]
+    fn stalemate_wins() {
+        let shatranj = Shatranj::create();
+        let mut board = Board::new(&shatranj, (8, 8), "k7/2Q5/1K6/8/8/8/8/8 b - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+
+    #[test]
+    fn bare_king_loses() {
+        let shatranj = Shatranj::create();
+        let mut board = Board::new(&shatranj, (8, 8), "4k3/8/8/8/8/8/8/R3K3 b - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+
+    #[test]
+    fn baring_back_draws() {
+        let shatranj = Shatranj::create();
+        let mut board = Board::new(&shatranj, (8, 8), "8/8/8/8/8/8/1r6/K6k w - - 0 1");
+
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+        let action = board.decode_action("a1b2", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Draw);
+    }
+}
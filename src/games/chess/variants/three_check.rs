@@ -0,0 +1,180 @@
+use crate::board::{
+    actions::Action,
+    fen::{
+        FenArgument, FenDecodeError, FenFullMoves, FenOptions, FenState, FenSubMoves,
+        FenTeamArgument,
+    },
+    game::{CustomStateUpdate, Game, GameResults, Resolution},
+    moves::GenerationMode,
+    Board, CustomState,
+};
+
+use crate::games::chess::{
+    ChessCastlingRights, ChessEnPassant, ChessMoveRestrictions, ChessPostProcess, ChessResolution,
+    BISHOP, CHESS_PIECE_VALUES, KING, KNIGHT, PAWN, QUEEN, ROOK,
+};
+
+const CHECKS: u32 = 3;
+
+/// Three-check stores the amount of checks each team still has to give in `board.state.custom`, indexed by team.
+pub struct ThreeCheckState;
+impl CustomStateUpdate for ThreeCheckState {
+    fn initial(&self) -> CustomState {
+        let mut custom = CustomState::new();
+        custom.push(CHECKS);
+        custom.push(CHECKS);
+        custom
+    }
+
+    fn update(&self, board: &mut Board, action: &Action) {
+        let enemy_king =
+            board.state.teams[board.state.moving_team as usize] & board.state.pieces[5];
+        if board.can_move(action.team, enemy_king, GenerationMode::Attacks) {
+            let remaining_checks = &mut board.state.custom[action.team as usize];
+            *remaining_checks = remaining_checks.saturating_sub(1);
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn CustomStateUpdate> {
+        Box::new(ThreeCheckState)
+    }
+}
+
+/// The remaining checks for each team, formatted like `+3+3` (white's remaining checks, then black's.)
+pub struct ThreeCheckCounters;
+impl FenArgument for ThreeCheckCounters {
+    fn encode(&self, board: &Board) -> String {
+        board
+            .state
+            .custom
+            .iter()
+            .map(|checks| format!("+{checks}"))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    fn decode(&self, board: &mut Board, arg: &str) -> Result<(), FenDecodeError> {
+        let counters = arg
+            .split('+')
+            .skip(1)
+            .map(|checks| checks.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                FenDecodeError::InvalidArgument(format!(
+                    "'{arg}' is not a valid check counter, as its counters aren't positive integers."
+                ))
+            })?;
+
+        if !arg.starts_with('+') || counters.len() != board.state.teams.len() {
+            return Err(FenDecodeError::InvalidArgument(format!(
+                "'{arg}' is not a valid check counter, which should look like `+3+3`."
+            )));
+        }
+
+        board.state.custom = counters.into_iter().collect();
+        Ok(())
+    }
+
+    fn duplicate(&self) -> Box<dyn FenArgument> {
+        Box::new(ThreeCheckCounters)
+    }
+}
+
+pub struct ThreeCheckResolution;
+impl Resolution for ThreeCheckResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        if let Some(team) = board.state.custom.iter().position(|checks| *checks == 0) {
+            return GameResults::Win(team as u32);
+        }
+
+        ChessResolution.resolve(board, legal_moves)
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(ThreeCheckResolution)
+    }
+}
+
+pub struct ThreeCheck;
+
+impl ThreeCheck {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![PAWN, KNIGHT, BISHOP, ROOK, QUEEN, KING],
+            piece_values: CHESS_PIECE_VALUES.to_vec(),
+            move_restrictions: Box::new(ChessMoveRestrictions),
+            resolution: Box::new(ThreeCheckResolution),
+            custom_state: Some(Box::new(ThreeCheckState)),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(ChessCastlingRights)),
+                    ("en passant".to_string(), Box::new(ChessEnPassant)),
+                    ("remaining checks".to_string(), Box::new(ThreeCheckCounters)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(ChessPostProcess),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::chess::ThreeCheck,
+    };
+
+    #[test]
+    fn startpos() {
+        let three_check = ThreeCheck::create();
+        let mut board = Board::new(
+            &three_check,
+            (8, 8),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - +3+3 0 1",
+        );
+
+        board.assert_perfts([20, 400, 8902, 197281]);
+    }
+
+    #[test]
+    fn counters_undo() {
+        let three_check = ThreeCheck::create();
+        let fen = "4k3/8/8/8/8/8/8/4K2R w - - +2+3 0 1";
+        let mut board = Board::new(&three_check, (8, 8), fen);
+
+        let check = board.decode_action("h1h8", GenerationMode::Normal).unwrap();
+        board.make_move(&check);
+        assert_eq!(board.to_fen(), "4k2R/8/8/8/8/8/8/4K3 b - - +1+3 1 2");
+
+        board.undo_move().unwrap();
+        assert_eq!(board.to_fen(), fen);
+
+        // Checks are found the same way from the attack maps.
+        let perfts = [1, 2, 3].map(|depth| board.perft(depth, true, GenerationMode::Normal));
+        board.enable_attack_maps();
+        board.make_move(&check);
+        assert_eq!(board.to_fen(), "4k2R/8/8/8/8/8/8/4K3 b - - +1+3 1 2");
+        board.undo_move().unwrap();
+        board.assert_perfts(perfts);
+    }
+
+    #[test]
+    fn third_check_wins() {
+        let three_check = ThreeCheck::create();
+        let mut board = Board::new(&three_check, (8, 8), "4k3/8/8/8/8/8/8/4K2R w - - +1+3 0 1");
+
+        let check = board.decode_action("h1h8", GenerationMode::Normal).unwrap();
+        board.make_move(&check);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+        assert_eq!(board.perft(1, true, GenerationMode::Normal), 0);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenOptions, FenState, FenTeamArgument, PostProcess},
+    game::{CustomStateUpdate, Game, GameResults, MoveRestrictions, Resolution},
+    moves::MoveBuffer,
+    BitBoard, Board, CustomState,
+};
+
+use super::{get_board_mask, get_group, get_neighbors, StonePiece};
+
+/// Scrambles `key` into a pseudorandom 64-bit number (using SplitMix64), so that each stone gets its own Zobrist key.
+fn get_zobrist_key(key: u64) -> u64 {
+    let mut key = key.wrapping_add(0x9E3779B97F4A7C15);
+    key = (key ^ (key >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    key = (key ^ (key >> 27)).wrapping_mul(0x94D049BB133111EB);
+    key ^ (key >> 31)
+}
+
+/// Hashes the stones on the board, ignoring which team is to move (positional superko only compares stones.)
+pub fn get_position_hash(board: &Board) -> u64 {
+    let mut hash = 0;
+    for (team, stones) in board.state.teams.iter().enumerate() {
+        for square in stones.iter_one_bits(board.state.squares) {
+            hash ^= get_zobrist_key((team as u64 * board.state.squares as u64) + square as u64);
+        }
+    }
+    hash
+}
+
+fn set_position_hash(board: &mut Board) {
+    let hash = get_position_hash(board);
+    board.state.custom[0] = hash as u32;
+    board.state.custom[1] = (hash >> 32) as u32;
+}
+
+fn read_position_hash(custom: &CustomState) -> u64 {
+    custom[0] as u64 | ((custom[1] as u64) << 32)
+}
+
+/// Go stores the hash of the current position in `board.state.custom`, so that `board.custom_history` keeps the hash of every earlier position for superko.
+pub struct GoState;
+impl CustomStateUpdate for GoState {
+    fn initial(&self) -> CustomState {
+        let mut custom = CustomState::new();
+        custom.push(0);
+        custom.push(0);
+        custom
+    }
+
+    #[allow(unused_variables)]
+    fn update(&self, board: &mut Board, action: &Action) {
+        set_position_hash(board);
+    }
+
+    fn duplicate(&self) -> Box<dyn CustomStateUpdate> {
+        Box::new(GoState)
+    }
+}
+
+/// Hashes the position loaded from the FEN, since it's the first position that superko compares against.
+pub struct GoPostProcess;
+impl PostProcess for GoPostProcess {
+    fn apply(&self, board: &mut Board) {
+        set_position_hash(board);
+    }
+
+    fn duplicate(&self) -> Box<dyn PostProcess> {
+        Box::new(GoPostProcess)
+    }
+}
+
+/// Stones can be placed on any empty square, unless that would be suicide or would repeat an earlier position (positional superko.)
+/// Passing is always legal.
+pub struct GoMoveRestrictions;
+impl MoveRestrictions for GoMoveRestrictions {
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        if action.is_pass() {
+            return true;
+        }
+
+        if StonePiece.is_suicide(board, action.to, action.team) {
+            return false;
+        }
+
+        board.make_move(action);
+        let hash = read_position_hash(&board.state.custom);
+        let is_repetition = board
+            .custom_history
+            .iter()
+            .any(|custom| read_position_hash(custom) == hash);
+        board.undo_move().unwrap();
+
+        !is_repetition
+    }
+
+    /// Passing is always available, so it's added once every placement has been checked.
+    fn filter_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        moves.push(Action::pass(board.state.moving_team));
+    }
+
+    fn has_move_filter(&self) -> bool {
+        true
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(GoMoveRestrictions)
+    }
+}
+
+/// Area scoring gives each team a point for every stone they have, and for every empty region bordered only by their stones.
+pub fn get_area_scores(board: &Board) -> Vec<u32> {
+    let mut scores = board
+        .state
+        .teams
+        .iter()
+        .map(|stones| stones.count_ones())
+        .collect::<Vec<_>>();
+
+    let mut empty = !board.state.all_pieces & get_board_mask(board);
+    while empty.is_set() {
+        let start = BitBoard::from_lsb(empty.bitscan_forward());
+        let region = get_group(board, start, empty);
+        let border = get_neighbors(board, region);
+
+        let owners = board
+            .state
+            .teams
+            .iter()
+            .enumerate()
+            .filter(|(_, stones)| (**stones & border).is_set())
+            .map(|(team, _)| team)
+            .collect::<Vec<_>>();
+        if let [owner] = owners[..] {
+            scores[owner] += region.count_ones();
+        }
+
+        empty &= !region;
+    }
+
+    scores
+}
+
+/// The game ends once both teams pass in a row, and is won by whoever has the higher area score.
+/// White is given `komi` extra points to make up for moving second.
+pub struct GoResolution {
+    pub komi: f32,
+}
+
+impl Resolution for GoResolution {
+    #[allow(unused_variables)]
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        let passes = board
+            .history
+            .iter()
+            .rev()
+            .take(2)
+            .filter(|history_move| history_move.action.is_pass())
+            .count();
+        if passes < 2 {
+            return GameResults::Ongoing;
+        }
+
+        let scores = get_area_scores(board);
+        let black = scores[0] as f32;
+        let white = scores[1] as f32 + self.komi;
+        if black > white {
+            GameResults::Win(0)
+        } else if white > black {
+            GameResults::Win(1)
+        } else {
+            GameResults::Draw
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(GoResolution { komi: self.komi })
+    }
+}
+
+/// Go is played by placing black (`b`) and white (`w`) stones, with black moving first.
+/// Any board that fits in a `BitBoard` works, although 9x9 is the standard size for now.
+///
+/// Its starting position on 9x9 is `9/9/9/9/9/9/9/9/9 b`.
+pub struct Go;
+
+impl Go {
+    pub fn create() -> Game {
+        Go::with_komi(7.5)
+    }
+
+    pub fn with_komi(komi: f32) -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![&StonePiece],
+            piece_values: vec![1],
+            move_restrictions: Box::new(GoMoveRestrictions),
+            resolution: Box::new(GoResolution { komi }),
+            custom_state: Some(Box::new(GoState)),
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![(
+                    "team to move".to_string(),
+                    Box::new(FenTeamArgument::Teams(vec!['b', 'w'])),
+                )],
+                post_process: Box::new(GoPostProcess),
+            },
+        }
+    }
+}
@@ -0,0 +1,113 @@
+mod game;
+mod stone;
+
+pub use game::*;
+pub use stone::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::go::{get_area_scores, Go},
+    };
+
+    const KO: &str = "1bw6/bw1w5/1bw6/9/9/9/9/9/9 b";
+    const WALLS: &str = "4bw3/4bw3/4bw3/4bw3/4bw3/4bw3/4bw3/4bw3/4bw3 b";
+
+    #[test]
+    fn startpos() {
+        let go = Go::create();
+        let mut board = Board::new(&go, (9, 9), "9/9/9/9/9/9/9/9/9 b");
+
+        board.assert_perfts([82, 6643]);
+    }
+
+    #[test]
+    fn small_boards() {
+        let go = Go::create();
+
+        let mut board = Board::new(&go, (3, 3), "3/3/3 b");
+        board.assert_perfts([10, 91, 738, 5281, 33384]);
+
+        board = Board::new(&go, (4, 4), "4/4/4/4 b");
+        board.assert_perfts([17, 273, 4112, 57984]);
+
+        board = Board::new(&go, (5, 5), "1bw2/bw1w1/1bw2/5/5 b");
+        board.assert_perfts([19, 341, 5786, 92797]);
+    }
+
+    #[test]
+    fn ko() {
+        let go = Go::create();
+        let mut board = Board::new(&go, (9, 9), KO);
+
+        board.assert_perfts([75, 5549]);
+
+        // Black takes the ko, and white can't immediately take it back.
+        let action = board.decode_action("cb", GenerationMode::Normal).unwrap();
+        assert!(board.is_capture(&action));
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "1bw6/b1bw5/1bw6/9/9/9/9/9/9 w");
+        assert!(board.decode_action("bb", GenerationMode::Normal).is_none());
+
+        // Once white plays elsewhere (and black answers), the position is new and the ko can be retaken.
+        let action = board.decode_action("ee", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        let action = board.decode_action("ef", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert!(board.decode_action("bb", GenerationMode::Normal).is_some());
+    }
+
+    #[test]
+    fn suicide() {
+        let go = Go::create();
+        let mut board = Board::new(&go, (9, 9), "1b7/b8/9/9/9/9/9/9/9 w");
+
+        assert!(board.decode_action("aa", GenerationMode::Normal).is_none());
+        assert!(board.decode_action("ba", GenerationMode::Normal).is_none());
+        assert!(board.decode_action("ab", GenerationMode::Normal).is_none());
+        assert!(board.decode_action("bb", GenerationMode::Normal).is_some());
+    }
+
+    #[test]
+    fn sgf_coordinates() {
+        let go = Go::create();
+        let mut board = Board::new(&go, (9, 9), "9/9/9/9/9/9/9/9/9 b");
+
+        let action = board.decode_action("cd", GenerationMode::Normal).unwrap();
+        assert_eq!(action.to, 3 * 9 + 2);
+        board.make_move(&action);
+
+        let action = board.decode_action("0000", GenerationMode::Normal).unwrap();
+        assert!(action.is_pass());
+        assert_eq!(board.encode_action(&action), "pass");
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "9/9/9/2b6/9/9/9/9/9 b");
+
+        board.undo_move().unwrap();
+        board.undo_move().unwrap();
+        assert_eq!(board.to_fen(), "9/9/9/9/9/9/9/9/9 b");
+    }
+
+    #[test]
+    fn area_scoring() {
+        for (komi, result) in [
+            (7.5, GameResults::Win(0)),
+            (9.0, GameResults::Draw),
+            (9.5, GameResults::Win(1)),
+        ] {
+            let go = Go::with_komi(komi);
+            let mut board = Board::new(&go, (9, 9), WALLS);
+            assert_eq!(get_area_scores(&board), vec![45, 36]);
+
+            let pass = board.decode_action("pass", GenerationMode::Normal).unwrap();
+            board.make_move(&pass);
+            assert_eq!(board.resolve(), GameResults::Ongoing);
+
+            let pass = board.decode_action("pass", GenerationMode::Normal).unwrap();
+            board.make_move(&pass);
+            assert_eq!(board.resolve(), result);
+            assert_eq!(board.perft(1, true, GenerationMode::Normal), 0);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::board::{
+    actions::{
+        Action, ActionKind, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard,
+        PreviousBoard,
+    },
+    moves::{GenerationMode, MoveBuffer},
+    pieces::{Piece, PieceSymbol},
+    BitBoard, Board,
+};
+
+const SGF_COORDS: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+
+/// Every square on the board, since the bits past the last square can be set by shifting stones down.
+pub fn get_board_mask(board: &Board) -> BitBoard {
+    BitBoard::starting_at_lsb(0, board.state.squares)
+}
+
+/// The squares orthogonally adjacent to any of the given stones.
+pub fn get_neighbors(board: &Board, stones: BitBoard) -> BitBoard {
+    let edges = &board.state.edges[0];
+    let cols = board.state.cols;
+
+    let neighbors = (stones & !edges.left).left(1)
+        | (stones & !edges.right).right(1)
+        | stones.up(1, cols)
+        | stones.down(1, cols);
+    neighbors & get_board_mask(board)
+}
+
+/// Flood fills from `start` through `area`, giving every square of `area` connected to it.
+pub fn get_group(board: &Board, start: BitBoard, area: BitBoard) -> BitBoard {
+    let mut group = start;
+    loop {
+        let next = (group | get_neighbors(board, group)) & area;
+        if next == group {
+            return group;
+        }
+        group = next;
+    }
+}
+
+pub fn get_liberties(board: &Board, group: BitBoard) -> BitBoard {
+    get_neighbors(board, group) & !board.state.all_pieces
+}
+
+/// Stones are never moved once they're placed, so all of their actions are placements.
+pub struct StonePiece;
+
+impl StonePiece {
+    /// The enemy groups that placing a stone on `to` would capture, because `to` is their last liberty.
+    pub fn get_captures(&self, board: &Board, to: u32, team: u32) -> BitBoard {
+        let to = BitBoard::from_lsb(to);
+        let enemies = board.state.teams[board.get_next_team(team) as usize];
+
+        let mut captures = BitBoard::new();
+        let mut adjacent = get_neighbors(board, to) & enemies;
+        while adjacent.is_set() {
+            let stone = BitBoard::from_lsb(adjacent.bitscan_forward());
+            let group = get_group(board, stone, enemies);
+            if get_liberties(board, group) == to {
+                captures |= group;
+            }
+            adjacent &= !group;
+        }
+        captures
+    }
+
+    /// Whether placing a stone on `to` would leave its own group without any liberties, without capturing anything.
+    pub fn is_suicide(&self, board: &Board, to: u32, team: u32) -> bool {
+        if self.get_captures(board, to, team).is_set() {
+            return false;
+        }
+
+        let to = BitBoard::from_lsb(to);
+        let group = get_group(board, to, board.state.teams[team as usize] | to);
+        (get_liberties(board, group) & !to).is_empty()
+    }
+}
+
+impl Piece for StonePiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::TeamSymbol(vec!['b', 'w'])
+    }
+
+    fn can_lookup(&self) -> bool {
+        false
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        BitBoard::new()
+    }
+
+    fn is_capture(&self, board: &Board, action: &Action) -> bool {
+        action.kind == ActionKind::Place
+            && self.get_captures(board, action.to, action.team).is_set()
+    }
+
+    /// Placements are written as SGF coordinates (the column, then the row from the top, eg. `cd`.)
+    fn encode_action(&self, board: &Board, action: &Action) -> String {
+        let row = action.to / board.state.cols;
+        let col = action.to % board.state.cols;
+        format!("{}{}", SGF_COORDS[col as usize], SGF_COORDS[row as usize])
+    }
+
+    /// Placing a stone captures every enemy group it takes the last liberty of.
+    fn make_placement(&self, board: &mut Board, action: &Action) {
+        let color = action.team as usize;
+        let enemy_color = board.get_next_team(action.team) as usize;
+        let to = BitBoard::from_lsb(action.to);
+        let captures = self.get_captures(board, action.to, action.team);
+
+        board.history.push(HistoryMove {
+            action: *action,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Team(IndexedPreviousBoard(
+                        enemy_color,
+                        board.state.teams[enemy_color],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        action.piece_type,
+                        board.state.pieces[action.piece_type],
+                    )),
+                ],
+            },
+        });
+
+        board.state.teams[color] |= to;
+        board.state.teams[enemy_color] &= !captures;
+        board.state.pieces[action.piece_type] =
+            (board.state.pieces[action.piece_type] | to) & !captures;
+        board.state.all_pieces = (board.state.all_pieces | to) & !captures;
+    }
+
+    /// Stones can be placed on any empty square, although `GoMoveRestrictions` rules out suicide and repeated positions.
+    #[allow(unused_variables)]
+    fn add_placements(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        let empty = !board.state.all_pieces & get_board_mask(board);
+
+        for to in empty.iter_one_bits(board.state.squares) {
+            actions.push(Action {
+                from: to,
+                to,
+                team,
+                piece_type,
+                info: 0,
+                kind: ActionKind::Place,
+            });
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenOptions, FenState, FenTeamArgument, NoPostProcess},
+    game::{Game, GameResults, NoRestrictions, Resolution},
+    pieces::Piece,
+    BitBoard, Board,
+};
+
+use super::MarkPiece;
+
+const MARK: &dyn Piece = &MarkPiece { gravity: false };
+const GRAVITY_MARK: &dyn Piece = &MarkPiece { gravity: true };
+
+/// Whether the given marks have `k` in a row horizontally, vertically or diagonally.
+///
+/// Each direction shifts the marks one square at a time (masking out the edge they'd wrap around), and ANDs them back into the run.
+/// After `k - 1` shifts, only the ends of lines that are at least `k` long are left.
+pub fn has_k_in_a_row(board: &Board, marks: BitBoard, k: u32) -> bool {
+    let edges = &board.state.edges[0];
+    let cols = board.state.cols;
+
+    let directions: [&dyn Fn(BitBoard) -> BitBoard; 4] = [
+        &|b| (b & !edges.right).right(1),
+        &|b| b.down(1, cols),
+        &|b| (b & !edges.right).right(1).down(1, cols),
+        &|b| (b & !edges.left).left(1).down(1, cols),
+    ];
+
+    directions.iter().any(|shift| {
+        let mut run = marks;
+        for _ in 1..k {
+            run = shift(run) & marks;
+        }
+        run.is_set()
+    })
+}
+
+/// A team wins once they have `k` marks in a row, and the game is drawn once the board is full.
+pub struct MnkResolution {
+    pub k: u32,
+}
+
+impl Resolution for MnkResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        for (team, marks) in board.state.teams.iter().enumerate() {
+            if has_k_in_a_row(board, *marks, self.k) {
+                return GameResults::Win(team as u32);
+            }
+        }
+
+        if legal_moves.is_empty() {
+            GameResults::Draw
+        } else {
+            GameResults::Ongoing
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(MnkResolution { k: self.k })
+    }
+}
+
+/// An m,n,k-game is played on an m x n board, where crosses (`x`) and noughts (`o`) take turns placing marks until one of them has `k` in a row.
+/// The board size comes from the board itself, so the builder only needs `k` and whether marks fall down their column.
+pub struct MnkGame {
+    pub k: u32,
+    pub gravity: bool,
+}
+
+impl MnkGame {
+    pub fn new(k: u32) -> MnkGame {
+        MnkGame { k, gravity: false }
+    }
+
+    pub fn with_gravity(self) -> MnkGame {
+        MnkGame {
+            gravity: true,
+            ..self
+        }
+    }
+
+    pub fn create(&self) -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![if self.gravity { GRAVITY_MARK } else { MARK }],
+            piece_values: vec![1],
+            move_restrictions: Box::new(NoRestrictions),
+            resolution: Box::new(MnkResolution { k: self.k }),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![(
+                    "team to move".to_string(),
+                    Box::new(FenTeamArgument::Teams(vec!['x', 'o'])),
+                )],
+                post_process: Box::new(NoPostProcess),
+            },
+        }
+    }
+}
+
+/// Tic-tac-toe is the 3,3,3-game.
+///
+/// Its starting position is `3/3/3 x`.
+pub struct TicTacToe;
+
+impl TicTacToe {
+    pub fn create() -> Game {
+        MnkGame::new(3).create()
+    }
+}
+
+/// Connect Four is the 6,7,4-game with gravity, where marks are dropped into the lowest empty square of a column.
+///
+/// Its starting position is `7/7/7/7/7/7 x`.
+pub struct ConnectFour;
+
+impl ConnectFour {
+    pub fn create() -> Game {
+        MnkGame::new(4).with_gravity().create()
+    }
+}
+
+/// Free-style Gomoku is won with five or more in a row.
+/// It's usually played on a 15x15 board, which doesn't fit in a `BitBoard` yet, so boards up to 11x11 have to be used for now.
+///
+/// Its starting position on 11x11 is `11/11/11/11/11/11/11/11/11/11/11 x`.
+pub struct Gomoku;
+
+impl Gomoku {
+    pub fn create() -> Game {
+        MnkGame::new(5).create()
+    }
+}
@@ -0,0 +1,63 @@
+use crate::board::{
+    actions::{Action, ActionKind},
+    moves::{GenerationMode, MoveBuffer},
+    pieces::{Piece, PieceSymbol},
+    BitBoard, Board,
+};
+
+/// Marks are never moved once they're placed, so all of their actions are placements.
+/// With `gravity`, marks can only be placed on the lowest empty square of each column (like Connect Four.)
+pub struct MarkPiece {
+    pub gravity: bool,
+}
+
+impl Piece for MarkPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::TeamSymbol(vec!['x', 'o'])
+    }
+
+    fn can_lookup(&self) -> bool {
+        false
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        BitBoard::new()
+    }
+
+    #[allow(unused_variables)]
+    fn add_placements(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        let squares = board.state.squares;
+        let mut placements = !board.state.all_pieces & BitBoard::starting_at_lsb(0, squares);
+        if self.gravity {
+            let supported =
+                board.state.edges[0].bottom | board.state.all_pieces.up(1, board.state.cols);
+            placements &= supported;
+        }
+
+        for to in placements.iter_one_bits(squares) {
+            actions.push(Action {
+                from: to,
+                to,
+                team,
+                piece_type,
+                info: 0,
+                kind: ActionKind::Place,
+            });
+        }
+    }
+}
@@ -0,0 +1,105 @@
+mod game;
+mod mark;
+
+pub use game::*;
+pub use mark::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::mnk::{ConnectFour, Gomoku, MnkGame, TicTacToe},
+    };
+
+    #[test]
+    fn tic_tac_toe() {
+        let tic_tac_toe = TicTacToe::create();
+        let mut board = Board::new(&tic_tac_toe, (3, 3), "3/3/3 x");
+
+        board.assert_perfts([9, 72, 504, 3024, 15120, 54720, 148176, 200448, 127872]);
+    }
+
+    #[test]
+    fn connect_four() {
+        let connect_four = ConnectFour::create();
+        let mut board = Board::new(&connect_four, (6, 7), "7/7/7/7/7/7 x");
+
+        board.assert_perfts([7, 49, 343, 2401, 16807, 117649, 823536]);
+    }
+
+    #[test]
+    fn connect_four_midgame() {
+        let connect_four = ConnectFour::create();
+        let mut board = Board::new(&connect_four, (6, 7), "7/7/7/2ox3/1oxx3/oxxo3 x");
+
+        board.assert_perfts([7, 49, 301, 2063, 12894]);
+    }
+
+    #[test]
+    fn gravity() {
+        let connect_four = ConnectFour::create();
+        let mut board = Board::new(&connect_four, (6, 7), "7/7/7/7/7/7 x");
+
+        let mut moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        moves.sort();
+        assert_eq!(moves, vec!["a1", "b1", "c1", "d1", "e1", "f1", "g1"]);
+
+        let action = board.decode_action("d1", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert!(board.decode_action("d2", GenerationMode::Normal).is_some());
+        assert!(board.decode_action("d3", GenerationMode::Normal).is_none());
+    }
+
+    #[test]
+    fn gomoku() {
+        let gomoku = Gomoku::create();
+        let mut board = Board::new(&gomoku, (11, 11), "11/11/11/11/11/11/11/11/11/11/11 x");
+        board.assert_perfts([121, 14520]);
+
+        board = Board::new(&gomoku, (7, 7), "7/1x5/2x1o2/3x3/o3o2/7/7 o");
+        board.assert_perfts([43, 1806, 74046]);
+    }
+
+    #[test]
+    fn lines() {
+        let tic_tac_toe = TicTacToe::create();
+
+        // The marks on c3, a2 and b2 are next to each other in the bitboard, but aren't a line.
+        let mut board = Board::new(&tic_tac_toe, (3, 3), "2x/xx1/3 o");
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+
+        board = Board::new(&tic_tac_toe, (3, 3), "2x/1x1/x2 o");
+        assert_eq!(board.resolve(), GameResults::Win(0));
+
+        board = Board::new(&tic_tac_toe, (3, 3), "o2/xo1/x1o x");
+        assert_eq!(board.resolve(), GameResults::Win(1));
+
+        board = Board::new(&tic_tac_toe, (3, 3), "xox/xox/oxo x");
+        assert_eq!(board.resolve(), GameResults::Draw);
+    }
+
+    #[test]
+    fn longer_lines_win() {
+        // Free-style gomoku counts overlines, so seven in a row still wins.
+        let game = MnkGame::new(5).create();
+        let mut board = Board::new(&game, (7, 7), "7/7/7/6x/7/7/7 x");
+
+        for square in ["a4", "b4", "c4", "e4", "f4"] {
+            let action = board.decode_action(square, GenerationMode::Normal).unwrap();
+            board.make_move(&action);
+            assert_eq!(board.resolve(), GameResults::Ongoing);
+            let action = board
+                .decode_action(&format!("{}1", &square[..1]), GenerationMode::Normal)
+                .unwrap();
+            board.make_move(&action);
+        }
+
+        let action = board.decode_action("d4", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+}
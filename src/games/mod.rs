@@ -1 +1,8 @@
+pub mod amazons;
+pub mod breakthrough;
+pub mod checkers;
 pub mod chess;
+pub mod go;
+pub mod mnk;
+pub mod reversi;
+pub mod xiangqi;
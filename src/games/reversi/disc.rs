@@ -0,0 +1,149 @@
+use crate::{
+    board::{
+        actions::{
+            Action, ActionKind, HistoryMove, HistoryState, HistoryUpdate, IndexedPreviousBoard,
+            PreviousBoard,
+        },
+        moves::{GenerationMode, MoveBuffer},
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::{get_blocked_ray, QueenPiece},
+};
+
+/// Discs are never moved once they're placed, so all of their actions are placements.
+pub struct DiscPiece;
+
+impl DiscPiece {
+    /// Every enemy disc that placing a disc on `to` would flip, by outflanking lines of them with one of `team`'s discs.
+    pub fn get_flips(&self, board: &Board, to: u32, team: u32) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, 0)
+            .expect("Could not find the disc attack lookup.");
+        let to_board = BitBoard::from_lsb(to);
+        let enemies = board.state.teams[board.get_next_team(team) as usize];
+
+        let mut flips = BitBoard::new();
+        for dir in 0..8 {
+            let ray = get_blocked_ray(to_board, to as usize, dir, lookup, !enemies);
+            let between = ray & enemies;
+            if between.is_set() && (ray & board.state.teams[team as usize]).is_set() {
+                flips |= between;
+            }
+        }
+        flips
+    }
+}
+
+impl Piece for DiscPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::TeamSymbol(vec!['b', 'w'])
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        QueenPiece.generate_lookup_moves(board, from)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    /// Gets the empty squares that this disc outflanks lines of enemy discs towards, which its team can place discs on.
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the disc attack lookup.");
+        let from_bit = from.bitscan_forward() as usize;
+        let enemies = board.state.teams[board.get_next_team(team) as usize];
+
+        let mut placements = BitBoard::new();
+        for dir in 0..8 {
+            let ray = get_blocked_ray(from, from_bit, dir, lookup, !enemies);
+            if (ray & enemies).is_set() {
+                placements |= ray & !board.state.all_pieces;
+            }
+        }
+        placements
+    }
+
+    /// Placing a disc flips every line of enemy discs it outflanks.
+    fn make_placement(&self, board: &mut Board, action: &Action) {
+        let color = action.team as usize;
+        let enemy_color = board.get_next_team(action.team) as usize;
+        let to = BitBoard::from_lsb(action.to);
+        let flips = self.get_flips(board, action.to, action.team);
+
+        board.history.push(HistoryMove {
+            action: *action,
+            state: HistoryState::Any {
+                all_pieces: PreviousBoard(board.state.all_pieces),
+                first_move: PreviousBoard(board.state.first_move),
+                updates: vec![
+                    HistoryUpdate::Team(IndexedPreviousBoard(color, board.state.teams[color])),
+                    HistoryUpdate::Team(IndexedPreviousBoard(
+                        enemy_color,
+                        board.state.teams[enemy_color],
+                    )),
+                    HistoryUpdate::Piece(IndexedPreviousBoard(
+                        action.piece_type,
+                        board.state.pieces[action.piece_type],
+                    )),
+                ],
+            },
+        });
+
+        board.state.teams[color] |= to | flips;
+        board.state.teams[enemy_color] &= !flips;
+        board.state.pieces[action.piece_type] |= to;
+        board.state.all_pieces |= to;
+    }
+
+    /// Discs never move, so they don't have any actions of their own (see `add_placements`.)
+    #[allow(unused_variables)]
+    fn add_actions(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        from: u32,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+    }
+
+    /// Several discs can outflank towards the same square, so the placements of every disc are combined before any are added.
+    fn add_placements(
+        &self,
+        actions: &mut dyn MoveBuffer,
+        board: &Board,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) {
+        let discs = board.state.pieces[piece_type] & board.state.teams[team as usize];
+
+        let mut placements = BitBoard::new();
+        for from in discs.iter_one_bits(board.state.squares) {
+            placements |= self.get_moves(board, BitBoard::from_lsb(from), piece_type, team, mode);
+        }
+
+        for to in placements.iter_one_bits(board.state.squares) {
+            actions.push(Action {
+                from: to,
+                to,
+                team,
+                piece_type,
+                info: 0,
+                kind: ActionKind::Place,
+            });
+        }
+    }
+}
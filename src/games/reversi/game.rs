@@ -0,0 +1,92 @@
+use crate::board::{
+    actions::Action,
+    fen::{FenOptions, FenState, FenTeamArgument, NoPostProcess},
+    game::{Game, GameResults, MoveRestrictions, Resolution},
+    moves::{GenerationMode, MoveBuffer},
+    Board,
+};
+
+use super::DiscPiece;
+
+/// Every placement has to flip at least one disc, and teams without any placements have to pass.
+pub struct ReversiMoveRestrictions;
+impl MoveRestrictions for ReversiMoveRestrictions {
+    #[allow(unused_variables)]
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        true
+    }
+
+    fn filter_moves(&self, board: &mut Board, moves: &mut dyn MoveBuffer) {
+        if moves.as_slice().is_empty() {
+            moves.push(Action::pass(board.state.moving_team));
+        }
+    }
+
+    fn has_move_filter(&self) -> bool {
+        true
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(ReversiMoveRestrictions)
+    }
+}
+
+/// The game ends once neither team can place a disc, and whoever has the most discs wins.
+pub struct ReversiResolution;
+impl Resolution for ReversiResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        if !legal_moves.iter().all(|action| action.is_pass()) {
+            return GameResults::Ongoing;
+        }
+
+        board.make_move(&Action::pass(board.state.moving_team));
+        let can_enemy_place = !board.generate_moves(GenerationMode::Normal).is_empty();
+        board.undo_move().unwrap();
+
+        if can_enemy_place {
+            return GameResults::Ongoing;
+        }
+
+        let discs = board.state.teams[0].count_ones();
+        let enemy_discs = board.state.teams[1].count_ones();
+        if discs > enemy_discs {
+            GameResults::Win(0)
+        } else if enemy_discs > discs {
+            GameResults::Win(1)
+        } else {
+            GameResults::Draw
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(ReversiResolution)
+    }
+}
+
+/// Reversi (Othello) is played on an 8x8 board, with black (`b`) discs moving before white (`w`) ones.
+/// Placements are written as the square they're placed on (eg. `d3`), and passes as `pass`.
+///
+/// Its starting position is `8/8/8/3bw3/3wb3/8/8/8 b`.
+pub struct Reversi;
+
+impl Reversi {
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![&DiscPiece],
+            piece_values: vec![1],
+            move_restrictions: Box::new(ReversiMoveRestrictions),
+            resolution: Box::new(ReversiResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![(
+                    "team to move".to_string(),
+                    Box::new(FenTeamArgument::Teams(vec!['b', 'w'])),
+                )],
+                post_process: Box::new(NoPostProcess),
+            },
+        }
+    }
+}
@@ -0,0 +1,83 @@
+mod disc;
+mod game;
+
+pub use disc::*;
+pub use game::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::reversi::Reversi,
+    };
+
+    #[test]
+    fn startpos() {
+        let reversi = Reversi::create();
+        let mut board = Board::new(&reversi, (8, 8), "8/8/8/3bw3/3wb3/8/8/8 b");
+
+        board.assert_perfts([4, 12, 56, 244, 1396, 8200, 55092]);
+    }
+
+    #[test]
+    fn flips() {
+        let reversi = Reversi::create();
+        let mut board = Board::new(&reversi, (8, 8), "8/8/8/3bw3/3wb3/8/8/8 b");
+
+        let mut moves = board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect::<Vec<_>>();
+        moves.sort();
+        assert_eq!(moves, vec!["c4", "d3", "e6", "f5"]);
+
+        let action = board.decode_action("d3", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.to_fen(), "8/8/8/3bw3/3bb3/3b4/8/8 w");
+
+        board.undo_move().unwrap();
+        assert_eq!(board.to_fen(), "8/8/8/3bw3/3wb3/8/8/8 b");
+    }
+
+    #[test]
+    fn reused_buffer() {
+        let reversi = Reversi::create();
+        let board = Board::new(&reversi, (8, 8), "8/8/8/3bw3/3wb3/8/8/8 b");
+
+        // Placements are only added once per square, whatever is already in the buffer.
+        let mut moves = board.generate_moves(GenerationMode::Normal);
+        board.generate_moves_into(&mut moves, GenerationMode::Normal);
+        assert_eq!(moves.len(), 8);
+        assert!(moves[..4]
+            .iter()
+            .zip(&moves[4..])
+            .all(|(first, second)| first.to == second.to));
+    }
+
+    #[test]
+    fn forced_pass() {
+        let reversi = Reversi::create();
+        // White can't outflank anything, but black can still place on c1.
+        let mut board = Board::new(&reversi, (8, 8), "8/8/8/8/8/8/8/bw6 w");
+
+        let moves = board.generate_legal_moves(GenerationMode::Normal);
+        assert_eq!(moves.len(), 1);
+        assert_eq!(board.encode_action(&moves[0]), "pass");
+        assert_eq!(board.resolve(), GameResults::Ongoing);
+
+        board.make_move(&moves[0]);
+        assert!(board.decode_action("c1", GenerationMode::Normal).is_some());
+    }
+
+    #[test]
+    fn game_over() {
+        let reversi = Reversi::create();
+        let mut board = Board::new(&reversi, (8, 8), "8/8/8/8/8/8/8/bw6 b");
+
+        let action = board.decode_action("c1", GenerationMode::Normal).unwrap();
+        board.make_move(&action);
+        assert_eq!(board.resolve(), GameResults::Win(0));
+        assert_eq!(board.perft(1, true, GenerationMode::Normal), 0);
+    }
+}
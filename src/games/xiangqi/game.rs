@@ -0,0 +1,133 @@
+use crate::{
+    board::{
+        actions::Action,
+        fen::{
+            FenFullMoves, FenOptions, FenState, FenSubMoves, FenTeamArgument, FenUnused,
+            NoPostProcess,
+        },
+        game::{Game, GameResults, MoveRestrictions, Resolution},
+        moves::GenerationMode,
+        pieces::Piece,
+        BitBoard, Board,
+    },
+    games::chess::pieces::RookPiece,
+};
+
+use super::pieces::{
+    AdvisorPiece, CannonPiece, ElephantPiece, GeneralPiece, HorsePiece, SoldierPiece,
+};
+
+pub(crate) const GENERAL: &dyn Piece = &GeneralPiece;
+pub(crate) const ADVISOR: &dyn Piece = &AdvisorPiece;
+pub(crate) const ELEPHANT: &dyn Piece = &ElephantPiece;
+pub(crate) const HORSE: &dyn Piece = &HorsePiece;
+pub(crate) const CHARIOT: &dyn Piece = &RookPiece;
+pub(crate) const CANNON: &dyn Piece = &CannonPiece;
+pub(crate) const SOLDIER: &dyn Piece = &SoldierPiece;
+
+/// The half of the board on a team's side of the river. Red (the first team) starts at the bottom of the board.
+pub fn get_home_side(board: &Board, team: u32) -> BitBoard {
+    let half = board.state.squares / 2;
+    match team {
+        0 => BitBoard::starting_at_lsb(half, half),
+        _ => BitBoard::starting_at_lsb(0, half),
+    }
+}
+
+/// The 3x3 palace in the middle of a team's first three ranks, which the general and advisors can't leave.
+pub fn get_palace(board: &Board, team: u32) -> BitBoard {
+    let cols = board.state.cols;
+    let first_row = match team {
+        0 => board.state.rows - 3,
+        _ => 0,
+    };
+
+    let mut palace = BitBoard::new();
+    for row in first_row..(first_row + 3) {
+        palace |= BitBoard::starting_at_lsb((row * cols) + (cols / 2) - 1, 3);
+    }
+    palace
+}
+
+/// Whether or not the given team's general is attacked. This includes the enemy general facing it on an open file.
+pub fn is_general_attacked(board: &Board, team: u32) -> bool {
+    let general = board.state.pieces[0] & board.state.teams[team as usize];
+    let enemy_team = board.get_next_team(team);
+    (board.get_move_mask(enemy_team, GenerationMode::Attacks) & general).is_set()
+}
+
+pub struct XiangqiMoveRestrictions;
+impl MoveRestrictions for XiangqiMoveRestrictions {
+    fn is_legal(&self, board: &mut Board, action: &Action) -> bool {
+        let current_team = board.state.moving_team;
+
+        board.make_move(action);
+        let in_check = is_general_attacked(board, current_team);
+        board.undo_move().unwrap();
+        !in_check
+    }
+
+    fn is_in_check(&self, board: &mut Board) -> bool {
+        is_general_attacked(board, board.state.moving_team)
+    }
+
+    fn gives_check(&self, board: &mut Board, action: &Action) -> bool {
+        let enemy_team = board.get_next_team(board.state.moving_team);
+
+        board.make_move(action);
+        let gives_check = is_general_attacked(board, enemy_team);
+        board.undo_move().unwrap();
+        gives_check
+    }
+
+    fn duplicate(&self) -> Box<dyn MoveRestrictions> {
+        Box::new(XiangqiMoveRestrictions)
+    }
+}
+
+/// Having no legal moves loses in xiangqi, whether or not your general is in check.
+pub struct XiangqiResolution;
+impl Resolution for XiangqiResolution {
+    fn resolve(&self, board: &mut Board, legal_moves: &[Action]) -> GameResults {
+        if legal_moves.is_empty() {
+            GameResults::Win(board.get_next_team(board.state.moving_team))
+        } else {
+            GameResults::Ongoing
+        }
+    }
+
+    fn duplicate(&self) -> Box<dyn Resolution> {
+        Box::new(XiangqiResolution)
+    }
+}
+
+pub struct Xiangqi;
+
+impl Xiangqi {
+    /// Xiangqi is played on a 9x10 board, with red (`w` in FENs) moving first.
+    pub fn create() -> Game {
+        Game {
+            teams: 2,
+            turns: 1,
+            pieces: vec![GENERAL, ADVISOR, ELEPHANT, HORSE, CHARIOT, CANNON, SOLDIER],
+            piece_values: vec![20000, 200, 200, 400, 900, 450, 100],
+            move_restrictions: Box::new(XiangqiMoveRestrictions),
+            resolution: Box::new(XiangqiResolution),
+            custom_state: None,
+            fen_options: FenOptions {
+                state: FenState { first_moves: false },
+                args: vec![
+                    (
+                        "team to move".to_string(),
+                        Box::new(FenTeamArgument::Teams(vec!['w', 'b'])),
+                    ),
+                    ("castling rights".to_string(), Box::new(FenUnused)),
+                    ("en passant".to_string(), Box::new(FenUnused)),
+                    ("half moves".to_string(), Box::new(FenSubMoves)),
+                    ("full moves".to_string(), Box::new(FenFullMoves)),
+                ],
+                post_process: Box::new(NoPostProcess),
+            },
+        }
+    }
+}
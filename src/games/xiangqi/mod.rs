@@ -0,0 +1,152 @@
+mod game;
+mod notation;
+pub mod pieces;
+
+pub use game::*;
+pub use notation::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        board::{game::GameResults, moves::GenerationMode, Board},
+        games::xiangqi::{decode_wxf, encode_wxf, Xiangqi},
+    };
+
+    const STARTPOS: &str = "rnbakabnr/9/1c5c1/p1p1p1p1p/9/9/P1P1P1P1P/1C5C1/9/RNBAKABNR w - - 0 1";
+
+    fn legal_moves(board: &mut Board) -> Vec<String> {
+        board
+            .generate_legal_moves(GenerationMode::Normal)
+            .iter()
+            .map(|action| board.encode_action(action))
+            .collect()
+    }
+
+    #[test]
+    fn startpos() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(&xiangqi, (10, 9), STARTPOS);
+
+        board.assert_perfts([44, 1920, 79666, 3290240]);
+    }
+
+    #[test]
+    fn midgame() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(
+            &xiangqi,
+            (10, 9),
+            "r1ba1a3/4kn3/2n1b4/pNp1p1p1p/4c4/6P2/P1P2R2P/1CcC5/9/2BAKAB2 w - - 0 1",
+        );
+
+        board.assert_perfts([38, 1128, 43929]);
+    }
+
+    #[test]
+    fn crossed_soldiers() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(
+            &xiangqi,
+            (10, 9),
+            "3ak4/4a4/2P1P4/1c7/p7p/P7P/9/4C4/4A4/3AK4 b - - 0 1",
+        );
+
+        board.assert_perfts([20, 457, 7600, 172337]);
+    }
+
+    #[test]
+    fn flying_general() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(&xiangqi, (10, 9), "3k5/9/9/9/9/9/9/9/9/4K4 w - - 0 1");
+
+        let mut moves = legal_moves(&mut board);
+        moves.sort();
+        assert_eq!(moves, vec!["e1e2", "e1f1"]);
+
+        // The general only attacks the enemy general along its file, and never the empty squares outside its palace.
+        board = Board::new(&xiangqi, (10, 9), "4k4/9/9/9/9/9/9/9/9/4K4 b - - 0 1");
+        let e5 = board.decode_position("e5".to_string()).unwrap();
+        let e10 = board.decode_position("e10".to_string()).unwrap();
+        assert!(!board.is_attacked(e5, 0));
+        assert!(board.is_attacked(e10, 0));
+        board.enable_attack_maps();
+        assert_eq!(board.attackers_to(e5, 0).count_ones(), 0);
+        assert_eq!(board.attackers_to(e10, 0).count_ones(), 1);
+    }
+
+    #[test]
+    fn cannon_screens() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(&xiangqi, (10, 9), "3k5/9/4p4/9/4P4/9/9/4C4/9/5K3 w - - 0 1");
+
+        let moves = legal_moves(&mut board);
+        assert!(moves.contains(&"e3e5".to_string()));
+        assert!(moves.contains(&"e3e8".to_string()));
+        assert!(!moves.contains(&"e3e7".to_string()));
+        assert!(!moves.contains(&"e3e9".to_string()));
+    }
+
+    #[test]
+    fn blocked_leapers() {
+        let xiangqi = Xiangqi::create();
+        // The horse on b1 has its legs blocked by the soldier on b2 and the elephant on c1, which in turn has one of its eyes blocked by that soldier.
+        let mut board = Board::new(
+            &xiangqi,
+            (10, 9),
+            "3k5/9/9/9/9/6B2/9/9/1P7/1NB1K4 w - - 0 1",
+        );
+
+        let moves = legal_moves(&mut board);
+        assert!(!moves.contains(&"b1a3".to_string()));
+        assert!(!moves.contains(&"b1c3".to_string()));
+        assert!(!moves.contains(&"b1d2".to_string()));
+        assert!(!moves.contains(&"c1a3".to_string()));
+        assert!(moves.contains(&"c1e3".to_string()));
+
+        // Elephants can't cross the river.
+        assert!(moves.contains(&"g5e3".to_string()));
+        assert!(!moves.contains(&"g5e7".to_string()));
+        assert!(!moves.contains(&"g5i7".to_string()));
+    }
+
+    #[test]
+    fn stalemate_loses() {
+        let xiangqi = Xiangqi::create();
+        // Black's general isn't in check, but every square it can step to is covered by the chariots or the soldier.
+        let mut board = Board::new(&xiangqi, (10, 9), "4k4/9/4P4/9/9/9/9/9/3R1R3/3K5 b - - 0 1");
+
+        assert!(legal_moves(&mut board).is_empty());
+        assert_eq!(board.resolve(), GameResults::Win(0));
+    }
+
+    #[test]
+    fn wxf_notation() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(&xiangqi, (10, 9), STARTPOS);
+
+        for (wxf, action) in [
+            ("C2.5", "h3e3"),
+            ("H8+7", "h10g8"),
+            ("H2+3", "h1g3"),
+            ("R9.8", "i10h10"),
+            ("P7+1", "c4c5"),
+        ] {
+            let decoded = decode_wxf(&mut board, wxf).expect(&format!("{wxf} is not legal"));
+            assert_eq!(board.encode_action(&decoded), action);
+            assert_eq!(encode_wxf(&board, &decoded), wxf);
+            board.make_move(&decoded);
+        }
+    }
+
+    #[test]
+    fn wxf_tandem_pieces() {
+        let xiangqi = Xiangqi::create();
+        let mut board = Board::new(&xiangqi, (10, 9), "3k5/9/9/9/9/9/4C4/9/4C4/5K3 w - - 0 1");
+
+        let front = decode_wxf(&mut board, "C+.6").unwrap();
+        assert_eq!(board.encode_action(&front), "e4d4");
+
+        let rear = decode_wxf(&mut board, "C-+1").unwrap();
+        assert_eq!(board.encode_action(&rear), "e2e3");
+    }
+}
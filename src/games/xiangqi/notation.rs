@@ -0,0 +1,81 @@
+use crate::board::{actions::Action, moves::GenerationMode, BitBoard, Board};
+
+/// The WXF letter of each piece type, in the order they're given in `Xiangqi::create`.
+const WXF_PIECES: [char; 7] = ['K', 'A', 'E', 'H', 'R', 'C', 'P'];
+
+/// Files are numbered 1-9 from right to left, from the point of view of the moving team.
+fn get_file(board: &Board, team: u32, col: u32) -> u32 {
+    match team {
+        0 => board.state.cols - col,
+        _ => col + 1,
+    }
+}
+
+/// How far forward (towards the enemy) a row is for the given team.
+fn get_progress(board: &Board, team: u32, row: u32) -> u32 {
+    match team {
+        0 => board.state.rows - 1 - row,
+        _ => row,
+    }
+}
+
+/// Encodes an action in WXF notation (eg. `C2.5` or `H8+7`): the piece, its file, a direction (`+` forward, `-` backward, `.` sideways) and then either the file it moves to, or how many ranks it moves for pieces moving straight along a file.
+///
+/// When another piece of the same kind shares its file, the file is replaced with `+` for the front piece and `-` for the rear one (eg. `C+.5`). Files with more than two of the same piece (which can only happen with soldiers) keep their file number.
+pub fn encode_wxf(board: &Board, action: &Action) -> String {
+    let cols = board.state.cols;
+    let team = action.team;
+    let (from_row, from_col) = (action.from / cols, action.from % cols);
+    let (to_row, to_col) = (action.to / cols, action.to % cols);
+
+    let letter = WXF_PIECES[action.piece_type];
+
+    let from_progress = get_progress(board, team, from_row);
+    let to_progress = get_progress(board, team, to_row);
+
+    let mut file_board = BitBoard::new();
+    for row in 0..board.state.rows {
+        file_board |= BitBoard::from_lsb((row * cols) + from_col);
+    }
+    let others = board.state.pieces[action.piece_type]
+        & board.state.teams[team as usize]
+        & file_board
+        & !BitBoard::from_lsb(action.from);
+    let others = others
+        .iter_one_bits(board.state.squares)
+        .map(|bit| get_progress(board, team, bit / cols))
+        .collect::<Vec<_>>();
+
+    let origin = match others.as_slice() {
+        [] => get_file(board, team, from_col).to_string(),
+        [other] if *other < from_progress => "+".to_string(),
+        [_] => "-".to_string(),
+        _ => get_file(board, team, from_col).to_string(),
+    };
+
+    let direction = if to_progress > from_progress {
+        '+'
+    } else if to_progress < from_progress {
+        '-'
+    } else {
+        '.'
+    };
+
+    // Advisors, elephants and horses never move straight, so they always give the file they land on.
+    let moves_diagonally = matches!(letter, 'A' | 'E' | 'H');
+    let destination = if direction == '.' || moves_diagonally {
+        get_file(board, team, to_col)
+    } else {
+        from_progress.abs_diff(to_progress)
+    };
+
+    format!("{letter}{origin}{direction}{destination}")
+}
+
+pub fn decode_wxf(board: &mut Board, action: &str) -> Option<Action> {
+    board
+        .generate_legal_moves(GenerationMode::Normal)
+        .iter()
+        .find(|el| encode_wxf(board, el) == action)
+        .copied()
+}
@@ -0,0 +1,48 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::xiangqi::get_palace,
+};
+
+/// The advisor steps one square diagonally, and can never leave its palace.
+pub struct AdvisorPiece;
+
+impl Piece for AdvisorPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('a')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let edges = &board.state.edges[0];
+        let cols = board.state.cols;
+
+        let moves = (from & !edges.top & !edges.left).up(1, cols).left(1)
+            | (from & !edges.top & !edges.right).up(1, cols).right(1)
+            | (from & !edges.bottom & !edges.left).down(1, cols).left(1)
+            | (from & !edges.bottom & !edges.right).down(1, cols).right(1);
+
+        vec![moves & get_palace(board, 0), moves & get_palace(board, 1)]
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the advisor attack lookup.");
+        lookup[from.bitscan_forward() as usize][team as usize]
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::{get_hopper_attacks, get_ray_attacks, RookPiece},
+};
+
+/// The cannon moves like a rook, but can only capture by jumping over exactly one piece.
+/// Only those captures count as attacks.
+pub struct CannonPiece;
+
+impl Piece for CannonPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('c')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        RookPiece.generate_lookup_moves(board, from)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the cannon attack lookup.");
+        let from_bit = from.bitscan_forward() as usize;
+
+        let mut moves = BitBoard::new();
+        for dir in 0..4 {
+            if mode != GenerationMode::Attacks {
+                moves |=
+                    get_ray_attacks(board, from, from_bit, dir, lookup) & !board.state.all_pieces;
+            }
+            moves |= get_hopper_attacks(board, from, from_bit, dir, lookup);
+        }
+        moves
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::{
+        chess::pieces::{generate_lame_leaps, get_lame_leaper_moves, LameLeap},
+        xiangqi::get_home_side,
+    },
+};
+
+const ELEPHANT_LEAPS: [LameLeap; 4] = [
+    LameLeap {
+        leg: (-1, -1),
+        target: (-2, -2),
+    },
+    LameLeap {
+        leg: (-1, 1),
+        target: (-2, 2),
+    },
+    LameLeap {
+        leg: (1, -1),
+        target: (2, -2),
+    },
+    LameLeap {
+        leg: (1, 1),
+        target: (2, 2),
+    },
+];
+
+/// The elephant jumps exactly two squares diagonally, but is blocked by a piece on the square in between (its eye), and can't cross the river.
+pub struct ElephantPiece;
+
+impl Piece for ElephantPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('b')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        generate_lame_leaps(board, from, &ELEPHANT_LEAPS)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the elephant attack lookup.");
+        get_lame_leaper_moves(board, &lookup[from.bitscan_forward() as usize])
+            & get_home_side(board, team)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::{
+        chess::pieces::{get_moves_ray, get_ray_attacks},
+        xiangqi::get_palace,
+    },
+};
+
+/// The general steps one square orthogonally, and can never leave its palace.
+/// Generals also can't face each other on an open file, so in attack mode the general also attacks the enemy general when nothing stands between them on its file (the flying general rule.)
+pub struct GeneralPiece;
+
+impl Piece for GeneralPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('k')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let edges = &board.state.edges[0];
+        let cols = board.state.cols;
+
+        let moves = (from & !edges.left).left(1)
+            | (from & !edges.right).right(1)
+            | (from & !edges.top).up(1, cols)
+            | (from & !edges.bottom).down(1, cols);
+
+        vec![
+            moves & get_palace(board, 0),
+            moves & get_palace(board, 1),
+            get_moves_ray(
+                from,
+                |b| b.up(1, cols),
+                |b| b.is_empty() || (b & edges.top).is_set(),
+            ),
+            get_moves_ray(
+                from,
+                |b| b.down(1, cols),
+                |b| b.is_empty() || (b & edges.bottom).is_set(),
+            ),
+        ]
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the general attack lookup.");
+        let from_bit = from.bitscan_forward() as usize;
+
+        let mut moves = lookup[from_bit][team as usize];
+        if mode == GenerationMode::Attacks {
+            // Only the enemy general is attacked along the file, since the general can't actually move along it.
+            let enemy_general = board.state.pieces[piece_type] & !board.state.teams[team as usize];
+            moves |= get_ray_attacks(board, from, from_bit, 2, lookup) & enemy_general;
+            moves |= get_ray_attacks(board, from, from_bit, 3, lookup) & enemy_general;
+        }
+        moves
+    }
+}
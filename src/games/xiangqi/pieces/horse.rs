@@ -0,0 +1,75 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::chess::pieces::{generate_lame_leaps, get_lame_leaper_moves, LameLeap},
+};
+
+const HORSE_LEAPS: [LameLeap; 8] = [
+    LameLeap {
+        leg: (-1, 0),
+        target: (-2, -1),
+    },
+    LameLeap {
+        leg: (-1, 0),
+        target: (-2, 1),
+    },
+    LameLeap {
+        leg: (1, 0),
+        target: (2, -1),
+    },
+    LameLeap {
+        leg: (1, 0),
+        target: (2, 1),
+    },
+    LameLeap {
+        leg: (0, -1),
+        target: (-1, -2),
+    },
+    LameLeap {
+        leg: (0, -1),
+        target: (1, -2),
+    },
+    LameLeap {
+        leg: (0, 1),
+        target: (-1, 2),
+    },
+    LameLeap {
+        leg: (0, 1),
+        target: (1, 2),
+    },
+];
+
+/// The horse moves like a knight, but first steps orthogonally, so it's blocked by a piece right next to it in that direction (its leg.)
+pub struct HorsePiece;
+
+impl Piece for HorsePiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('n')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        generate_lame_leaps(board, from, &HORSE_LEAPS)
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the horse attack lookup.");
+        get_lame_leaper_moves(board, &lookup[from.bitscan_forward() as usize])
+    }
+}
@@ -0,0 +1,13 @@
+mod advisor;
+mod cannon;
+mod elephant;
+mod general;
+mod horse;
+mod soldier;
+
+pub use advisor::*;
+pub use cannon::*;
+pub use elephant::*;
+pub use general::*;
+pub use horse::*;
+pub use soldier::*;
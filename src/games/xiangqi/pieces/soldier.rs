@@ -0,0 +1,54 @@
+use crate::{
+    board::{
+        moves::GenerationMode,
+        pieces::{Piece, PieceSymbol},
+        AttackDirections, BitBoard, Board,
+    },
+    games::xiangqi::get_home_side,
+};
+
+/// The soldier steps one square forward, and once it's crossed the river, it can also step sideways.
+/// Soldiers never promote, and capture the same way they move.
+pub struct SoldierPiece;
+
+impl Piece for SoldierPiece {
+    fn get_piece_symbol(&self) -> PieceSymbol {
+        PieceSymbol::Char('p')
+    }
+
+    fn generate_lookup_moves(&self, board: &Board, from: BitBoard) -> AttackDirections {
+        let edges = &board.state.edges[0];
+        let cols = board.state.cols;
+        let sideways = (from & !edges.left).left(1) | (from & !edges.right).right(1);
+
+        let mut lookups = vec![
+            (from & !edges.top).up(1, cols),
+            (from & !edges.bottom).down(1, cols),
+        ];
+        for (team, lookup) in lookups.iter_mut().enumerate() {
+            if (from & get_home_side(board, team as u32)).is_empty() {
+                *lookup |= sideways;
+            }
+        }
+        lookups
+    }
+
+    fn can_lookup(&self) -> bool {
+        true
+    }
+
+    #[allow(unused_variables)]
+    fn get_moves(
+        &self,
+        board: &Board,
+        from: BitBoard,
+        piece_type: usize,
+        team: u32,
+        mode: GenerationMode,
+    ) -> BitBoard {
+        let lookup = self
+            .get_attack_lookup(board, piece_type)
+            .expect("Could not find the soldier attack lookup.");
+        lookup[from.bitscan_forward() as usize][team as usize]
+    }
+}
@@ -1,4 +1,7 @@
-use monster_chess::{board::Board, games::chess::Chess};
+use monster_chess::{
+    board::{moves::GenerationMode, Board},
+    games::chess::Chess,
+};
 use std::env;
 use std::time::Duration;
 
@@ -24,12 +27,12 @@ fn main() {
 
 
     let start = get_time_ms();
-    let perft = board.perft(5, true);
+    let perft = board.perft(5, true, GenerationMode::Normal);
     let end = get_time_ms();
     println!("perft(5): {} in {}ms", perft, end - start);
 
     let start = get_time_ms();
-    let perft = board.perft(5, false);
+    let perft = board.perft(5, false, GenerationMode::Normal);
     let end = get_time_ms();
     println!("perft<No Legality>(5): {} in {}ms", perft, end - start);
 }